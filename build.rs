@@ -0,0 +1,264 @@
+//Generates the lookup tables of evaluate_bits and evaluate_seven into OUT_DIR, where src/tables.rs
+//includes them. Every entry is a HandRank packed as in src/bits.rs, or 0 where no hand lands.
+//Building them here rather than in const fns keeps the crate quick to compile, and lets one walk
+//over rank multisets and one pair of rank functions serve both evaluators.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+//Only part of it is needed here.
+#[allow(dead_code)]
+#[path = "src/bits.rs"]
+mod bits;
+
+use bits::{pack, straight_high, PRIMES};
+
+const RANKS: usize = 13;
+const CARDS: usize = 7;
+
+//Ranking::value() of each category.
+const HIGH_CARD: u8 = 1;
+const ONE_PAIR: u8 = 2;
+const TWO_PAIR: u8 = 3;
+const THREE_OF_A_KIND: u8 = 4;
+const STRAIGHT: u8 = 5;
+const FLUSH: u8 = 6;
+const FULL_HOUSE: u8 = 7;
+const FOUR_OF_A_KIND: u8 = 8;
+const STRAIGHT_FLUSH: u8 = 9;
+const FIVE_OF_A_KIND: u8 = 10;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/bits.rs");
+
+    let mut out = String::new();
+
+    let flushes: Vec<u32> = (0..1 << RANKS)
+        .map(|ranks: usize| match ranks.count_ones() {
+            5..=7 => flush(ranks),
+            _ => 0,
+        })
+        .collect();
+    write_table(
+        &mut out,
+        "The best flush or straight flush of a suit holding five to seven cards, by its rank bits.",
+        "FLUSHES",
+        "u32",
+        flushes.iter().map(|rank| format!("{:#x}", rank)),
+    );
+
+    let distinct: Vec<u32> = (0..1 << RANKS)
+        .map(|ranks: usize| match ranks.count_ones() {
+            5 => without_flush(&counts_of(ranks)),
+            _ => 0,
+        })
+        .collect();
+    write_table(
+        &mut out,
+        "Five cards of different ranks and not all of a suit, by their rank bits.",
+        "DISTINCT",
+        "u32",
+        distinct.iter().map(|rank| format!("{:#x}", rank)),
+    );
+
+    let mut products: Vec<(u32, u32)> = multisets(5, 5)
+        .iter()
+        .filter(|counts| counts.iter().any(|&count| count > 1))
+        .map(|counts| (product(counts), without_flush(counts)))
+        .collect();
+    products.sort_unstable();
+    write_table(
+        &mut out,
+        "Five cards with a repeated rank, by the product of their rank primes, sorted for a binary \
+         search.",
+        "PRODUCTS",
+        "(u32, u32)",
+        products
+            .iter()
+            .map(|(product, rank)| format!("({}, {:#x})", product, rank)),
+    );
+
+    let offsets = offsets();
+    let sevens = multisets(CARDS, 4);
+    let mut multiset_ranks = vec![0; sevens.len()];
+    for counts in &sevens {
+        let mut index = 0;
+        let mut left = CARDS;
+        for (rank, &count) in counts.iter().enumerate() {
+            index += offsets[rank][left][count as usize];
+            left -= count as usize;
+        }
+        multiset_ranks[index as usize] = without_flush(counts);
+    }
+    write_table(
+        &mut out,
+        "Seven cards holding no flush, by the perfect hash of their rank counts through OFFSETS.",
+        "MULTISET_RANKS",
+        "u32",
+        multiset_ranks.iter().map(|rank| format!("{:#x}", rank)),
+    );
+
+    out.push_str(
+        "//OFFSETS[i][k][c]: how many multisets of seven ranks come before those holding c of rank \
+         i,\n//given k cards left for rank i and the ranks above it. Summed over the ranks, this is \
+         the\n//perfect hash.\n",
+    );
+    out.push_str(&format!(
+        "pub(crate) const OFFSETS: [[[u32; 5]; {}]; {}] = {:?};\n",
+        CARDS + 1,
+        RANKS,
+        offsets
+    ));
+
+    let path = Path::new(&env::var_os("OUT_DIR").expect("cargo sets OUT_DIR")).join("tables.rs");
+    fs::write(path, out).expect("the tables should be written to OUT_DIR");
+}
+
+fn write_table(
+    out: &mut String,
+    comment: &str,
+    name: &str,
+    kind: &str,
+    entries: impl ExactSizeIterator<Item = String>,
+) {
+    out.push_str(&format!("//{}\n", comment));
+    out.push_str(&format!(
+        "pub(crate) static {}: [{}; {}] = [\n",
+        name,
+        kind,
+        entries.len()
+    ));
+    for entry in entries {
+        out.push_str(&format!("    {},\n", entry));
+    }
+    out.push_str("];\n");
+}
+
+//A HandRank under the standard order, where the strength of a category is its Ranking::value().
+fn rank(category: u8, tie_break: &[u8]) -> u32 {
+    pack(category, category, tie_break)
+}
+
+//The best flush or straight flush among the rank bits of a suit, bit i standing for rank i.
+fn flush(ranks: usize) -> u32 {
+    let values = (ranks as u16) << 2;
+    match straight_high(values, Some(5)) {
+        Some(high) => rank(STRAIGHT_FLUSH, &[high]),
+        None => rank(FLUSH, &descending(values).take(5).collect::<Vec<_>>()),
+    }
+}
+
+//The best hand five to seven cards of these rank counts make, leaving flushes aside.
+fn without_flush(counts: &[u8; RANKS]) -> u32 {
+    //(count, value) for every value present, largest group first and then highest value first.
+    let mut groups: Vec<(u8, u8)> = (0..RANKS)
+        .filter(|&rank| counts[rank] > 0)
+        .map(|rank| (counts[rank], rank as u8 + 2))
+        .collect();
+    groups.sort_unstable_by(|a, b| b.cmp(a));
+    let values = groups
+        .iter()
+        .fold(0u16, |values, &(_, value)| values | 1 << value);
+    let straight = straight_high(values, Some(5));
+
+    let size = |index: usize| groups.get(index).map_or(0, |group| group.0);
+    let (category, made, kickers) = match (size(0), size(1)) {
+        (5, _) => (FIVE_OF_A_KIND, vec![groups[0].1], 0),
+        (4, _) => (FOUR_OF_A_KIND, vec![groups[0].1], 1),
+        (3, 2) | (3, 3) => {
+            //A second trips can only play as a pair, and only when higher than every pair.
+            let two = groups[1..]
+                .iter()
+                .filter(|group| group.0 >= 2)
+                .map(|group| group.1)
+                .max()
+                .expect("a full house has a pair");
+            (FULL_HOUSE, vec![groups[0].1, two], 0)
+        }
+        _ if straight.is_some() => (STRAIGHT, straight.into_iter().collect(), 0),
+        (3, _) => (THREE_OF_A_KIND, vec![groups[0].1], 2),
+        (2, 2) => (TWO_PAIR, vec![groups[0].1, groups[1].1], 1),
+        (2, _) => (ONE_PAIR, vec![groups[0].1], 3),
+        _ => (HIGH_CARD, Vec::new(), 5),
+    };
+    //The highest of the other values fill out the five cards.
+    let rest = made
+        .iter()
+        .fold(values, |rest, &value| rest & !(1 << value));
+    let tie_break: Vec<u8> = made
+        .into_iter()
+        .chain(descending(rest).take(kickers))
+        .collect();
+    rank(category, &tie_break)
+}
+
+//The values whose bits are set, highest first.
+fn descending(values: u16) -> impl Iterator<Item = u8> {
+    (2..=14)
+        .rev()
+        .filter(move |&value| values & (1 << value) != 0)
+}
+
+fn counts_of(ranks: usize) -> [u8; RANKS] {
+    let mut counts = [0; RANKS];
+    for (rank, count) in counts.iter_mut().enumerate() {
+        *count = (ranks >> rank & 1) as u8;
+    }
+    counts
+}
+
+fn product(counts: &[u8; RANKS]) -> u32 {
+    counts
+        .iter()
+        .zip(PRIMES.iter())
+        .map(|(&count, &prime)| prime.pow(count as u32))
+        .product()
+}
+
+//The count of each rank for every multiset of `cards` ranks, none of them held more than `most`
+//times, in lexicographic order.
+fn multisets(cards: usize, most: u8) -> Vec<[u8; RANKS]> {
+    fn fill(counts: &mut [u8; RANKS], rank: usize, left: u8, most: u8, all: &mut Vec<[u8; RANKS]>) {
+        if rank == RANKS {
+            if left == 0 {
+                all.push(*counts);
+            }
+            return;
+        }
+        for count in 0..=left.min(most) {
+            counts[rank] = count;
+            fill(counts, rank + 1, left - count, most, all);
+        }
+        counts[rank] = 0;
+    }
+    let mut all = Vec::new();
+    fill(&mut [0; RANKS], 0, cards as u8, most, &mut all);
+    all
+}
+
+//OFFSETS as evaluate_seven uses them, built from WAYS[n][k]: the ways to hold k cards among n
+//ranks, at most four of each.
+fn offsets() -> [[[u32; 5]; CARDS + 1]; RANKS] {
+    let mut ways = [[0; CARDS + 1]; RANKS + 1];
+    ways[0][0] = 1;
+    for n in 1..=RANKS {
+        for k in 0..=CARDS {
+            ways[n][k] = (0..=k.min(4)).map(|count| ways[n - 1][k - count]).sum();
+        }
+    }
+
+    let mut offsets = [[[0; 5]; CARDS + 1]; RANKS];
+    for (rank, offsets) in offsets.iter_mut().enumerate() {
+        for (left, offsets) in offsets.iter_mut().enumerate() {
+            for count in 1..5 {
+                offsets[count] = offsets[count - 1];
+                if count <= left {
+                    offsets[count] += ways[RANKS - 1 - rank][left - (count - 1)];
+                }
+            }
+        }
+    }
+    offsets
+}
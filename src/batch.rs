@@ -0,0 +1,173 @@
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::error::PokerError;
+use crate::hand::{parse_showdown, Hand};
+use crate::hand_rank::{best_indices, HandRank};
+use crate::rng::Rng;
+use crate::rules::RankingRules;
+
+//Simulations run in chunks of this many iterations, each with its own stream of random numbers.
+const CHUNK: u64 = 4096;
+
+/// [`winning_hands`] for many showdowns at once, e.g. a day's worth of recorded tables.
+///
+/// Each entry of `showdowns` is the list of hands shown down at one table, and the results come
+/// back in the same order. Parsing and ranking reuse the same buffers from one showdown to the
+/// next. With the `parallel` feature the showdowns are split across threads with rayon, without
+/// changing any result.
+///
+/// [`winning_hands`]: crate::winning_hands
+pub fn winning_hands_batch<'a, S>(showdowns: &[S]) -> Vec<Option<Vec<&'a str>>>
+where
+    S: AsRef<[&'a str]> + Sync,
+{
+    map_showdowns(showdowns, |scratch, hands| {
+        scratch.winners(hands.as_ref(), RankingRules::High).ok()
+    })
+}
+
+/// Fallible version of [`winning_hands_batch`]. A showdown which fails does not stop the rest;
+/// its error is reported as by [`try_winning_hands`], in its place among the results.
+///
+/// [`try_winning_hands`]: crate::try_winning_hands
+pub fn try_winning_hands_batch<'a, S>(showdowns: &[S]) -> Vec<Result<Vec<&'a str>, PokerError>>
+where
+    S: AsRef<[&'a str]> + Sync,
+{
+    try_winning_hands_batch_with(showdowns, RankingRules::High)
+}
+
+/// Like [`try_winning_hands_batch`], but ranks the hands under the given rules, as
+/// [`winning_hands_with`] does.
+///
+/// [`winning_hands_with`]: crate::winning_hands_with
+pub fn try_winning_hands_batch_with<'a, S>(
+    showdowns: &[S],
+    rules: RankingRules,
+) -> Vec<Result<Vec<&'a str>, PokerError>>
+where
+    S: AsRef<[&'a str]> + Sync,
+{
+    map_showdowns(showdowns, |scratch, hands| {
+        scratch.winners(hands.as_ref(), rules)
+    })
+}
+
+/// [`winning_hands_batch`] for hands which are already parsed, so nothing is parsed again.
+///
+/// Returns the winning hands of each showdown, the very [`Hand`]s passed in. As with
+/// [`rank_parsed_hands`], the hands of a showdown are not checked against each other.
+///
+/// [`rank_parsed_hands`]: crate::rank_parsed_hands
+pub fn winning_parsed_hands_batch<S>(showdowns: &[S]) -> Vec<Vec<&Hand>>
+where
+    S: AsRef<[Hand]> + Sync,
+{
+    map_showdowns(showdowns, |scratch, hands| {
+        let hands = hands.as_ref();
+        scratch.ranks.clear();
+        scratch
+            .ranks
+            .extend(hands.iter().map(|hand| Some(hand.rank())));
+        best_indices(&scratch.ranks)
+            .into_iter()
+            .map(|index| &hands[index])
+            .collect()
+    })
+}
+
+//The buffers a showdown is settled with, kept from one showdown to the next.
+#[derive(Default)]
+pub(crate) struct Scratch {
+    hands: Vec<Hand>,
+    //The rank of every hand, or None for one which does not qualify.
+    ranks: Vec<Option<HandRank>>,
+}
+
+impl Scratch {
+    //Picks the winners exactly as try_winning_hands_with describes, reporting the same errors.
+    pub(crate) fn winners<'a>(
+        &mut self,
+        hands: &[&'a str],
+        rules: RankingRules,
+    ) -> Result<Vec<&'a str>, PokerError> {
+        parse_showdown(hands, &mut self.hands)?;
+
+        self.ranks.clear();
+        self.ranks.extend(
+            self.hands
+                .iter()
+                .map(|hand| Some(hand.rank_with(rules)).filter(|&rank| rules.qualifies(rank))),
+        );
+
+        // HandRank is a total order over category and kickers, so the winners are simply every
+        // qualifying hand sharing the maximum.
+        Ok(best_indices(&self.ranks)
+            .into_iter()
+            .filter(|&index| self.ranks[index].is_some())
+            .map(|index| hands[index])
+            .collect())
+    }
+}
+
+#[cfg(feature = "parallel")]
+fn map_showdowns<'s, S, T>(
+    showdowns: &'s [S],
+    settle: impl Fn(&mut Scratch, &'s S) -> T + Sync,
+) -> Vec<T>
+where
+    S: Sync,
+    T: Send,
+{
+    showdowns
+        .par_iter()
+        .map_init(Scratch::default, |scratch, showdown| {
+            settle(scratch, showdown)
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "parallel"))]
+fn map_showdowns<'s, S, T>(
+    showdowns: &'s [S],
+    settle: impl Fn(&mut Scratch, &'s S) -> T + Sync,
+) -> Vec<T>
+where
+    S: Sync,
+    T: Send,
+{
+    let mut scratch = Scratch::default();
+    showdowns
+        .iter()
+        .map(|showdown| settle(&mut scratch, showdown))
+        .collect()
+}
+
+//Runs `iterations` iterations of a simulation in chunks, returning what each chunk gave in order.
+//The first chunk draws from the seed itself and every later one from a jump further on, so the
+//chunks never share random numbers. They come out the same whether they run one after another or
+//across threads, so a seed gives the same result with or without the parallel feature.
+pub(crate) fn simulate<T, F>(iterations: u64, seed: u64, run: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(&mut Rng, u64) -> T + Sync,
+{
+    let mut rng = Rng::new(seed);
+    let chunks: Vec<(Rng, u64)> = (0..iterations)
+        .step_by(CHUNK as usize)
+        .map(|start| {
+            let stream = rng.clone();
+            rng.jump();
+            (stream, CHUNK.min(iterations - start))
+        })
+        .collect();
+
+    #[cfg(feature = "parallel")]
+    let chunks = chunks.into_par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let chunks = chunks.into_iter();
+    chunks
+        .map(|(mut rng, count)| run(&mut rng, count))
+        .collect()
+}
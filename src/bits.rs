@@ -0,0 +1,39 @@
+//Bit layouts and the straight detector, shared with build.rs, which includes this file to generate
+//the lookup tables. Nothing here may refer to the rest of the crate.
+
+/// The prime standing for each rank in [`Card::to_bits`], from the two up to the ace.
+///
+/// [`Card::to_bits`]: crate::Card::to_bits
+pub(crate) const PRIMES: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+//Layout of a packed HandRank, from the most significant bits down:
+//  bits 24..28  strength of the category, its place in the category order of the rules (1..10)
+//  bits 20..24  Ranking::value() of the category
+//  bits  0..20  up to five tie-break rank values, one nibble each, most important first
+//Unused nibbles are zero. As every field is ordered by significance, comparing the raw u32
+//compares the category first and then each tie-break rank in turn. Under the standard order the
+//strength is just Ranking::value(); short deck reorders categories, which only moves the strength.
+const STRENGTH_SHIFT: u32 = 24;
+pub(crate) const CATEGORY_SHIFT: u32 = 20;
+pub(crate) const TIE_BREAK_SLOTS: usize = 5;
+
+pub(crate) fn pack(strength: u8, category: u8, tie_break: &[u8]) -> u32 {
+    let packed = tie_break
+        .iter()
+        .chain(core::iter::repeat(&0))
+        .take(TIE_BREAK_SLOTS)
+        .fold(0, |acc, &value| (acc << 4) | value as u32);
+    ((strength as u32) << STRENGTH_SHIFT) | ((category as u32) << CATEGORY_SHIFT) | packed
+}
+
+//The highest card of the best straight among the values, bit v standing for value v, or None if
+//there is none. An ace (14) may also play below a run of four ending at `wheel`, e.g. 5 for the
+//five-high A-2-3-4-5; None if an ace may not play low.
+pub(crate) fn straight_high(values: u16, wheel: Option<u8>) -> Option<u8> {
+    (6..=14u8)
+        .rev()
+        .find(|&high| (values >> (high - 4)) & 0x1F == 0x1F)
+        .or_else(|| {
+            wheel.filter(|&wheel| values & (1 << 14) != 0 && (values >> (wheel - 3)) & 0xF == 0xF)
+        })
+}
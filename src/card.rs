@@ -0,0 +1,438 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::bits::PRIMES;
+use crate::error::{text, CardError};
+
+/// One of the four French suits.
+//Implementing types for different suits will allow for checking equality later
+//if necessary
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Suit {
+    Hearts,
+    Diamonds,
+    Spades,
+    Clubs,
+}
+
+const RANKS: u8 = 13;
+
+impl Suit {
+    /// Every suit, in the order they are numbered in [`Card::index`], which is also
+    /// [`Card::deck`] order.
+    pub const ALL: [Suit; 4] = [Suit::Hearts, Suit::Diamonds, Suit::Spades, Suit::Clubs];
+
+    //Position in ALL.
+    pub(crate) fn index(&self) -> u8 {
+        match self {
+            Suit::Hearts => 0,
+            Suit::Diamonds => 1,
+            Suit::Spades => 2,
+            Suit::Clubs => 3,
+        }
+    }
+
+    //The letter, name and symbol of each suit, in that order.
+    fn notations(&self) -> (char, &'static str, char) {
+        match self {
+            Suit::Hearts => ('h', "Hearts", '♥'),
+            Suit::Diamonds => ('d', "Diamonds", '♦'),
+            Suit::Spades => ('s', "Spades", '♠'),
+            Suit::Clubs => ('c', "Clubs", '♣'),
+        }
+    }
+}
+
+/// Accepts the suit letter in either case (`h`, `H`) or its symbol (`♥`, or the outlined `♡`).
+impl TryFrom<char> for Suit {
+    type Error = CardError;
+
+    fn try_from(suit: char) -> Result<Self, Self::Error> {
+        match suit.to_ascii_lowercase() {
+            'h' | '♥' | '♡' => Ok(Suit::Hearts),
+            'd' | '♦' | '♢' => Ok(Suit::Diamonds),
+            's' | '♠' | '♤' => Ok(Suit::Spades),
+            'c' | '♣' | '♧' => Ok(Suit::Clubs),
+            _ => Err(CardError::IncorrectSuit { suit }),
+        }
+    }
+}
+
+/// Accepts anything [`Suit::try_from`] does, or the suit's name in any case (`hearts`).
+impl FromStr for Suit {
+    type Err = CardError;
+
+    fn from_str(suit: &str) -> Result<Self, Self::Err> {
+        let mut chars = suit.chars();
+        match (chars.next(), chars.next()) {
+            (Some(symbol), None) => Suit::try_from(symbol),
+            _ => Suit::ALL
+                .iter()
+                .find(|candidate| candidate.notations().1.eq_ignore_ascii_case(suit))
+                .copied()
+                .ok_or(CardError::IncorrectSuit {
+                    suit: suit.chars().next().unwrap_or(' '),
+                }),
+        }
+    }
+}
+
+/// The rank of a card, independent of its suit.
+///
+/// Aces rank above kings. An ace may still play below a two to complete a five-high straight
+/// (the "wheel"), but that is decided by the evaluator rather than by a separate rank.
+// Each rank is necessary when checking for equality and attributing a value
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Rank {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Rank {
+    /// Every rank, from the two up to the ace.
+    pub const ALL: [Rank; 13] = [
+        Rank::Two,
+        Rank::Three,
+        Rank::Four,
+        Rank::Five,
+        Rank::Six,
+        Rank::Seven,
+        Rank::Eight,
+        Rank::Nine,
+        Rank::Ten,
+        Rank::Jack,
+        Rank::Queen,
+        Rank::King,
+        Rank::Ace,
+    ];
+
+    /// The value used to order ranks, from 2 (two) to 14 (ace). Every rank has its own value.
+    pub fn value(&self) -> u8 {
+        match self {
+            Rank::Two => 2,
+            Rank::Three => 3,
+            Rank::Four => 4,
+            Rank::Five => 5,
+            Rank::Six => 6,
+            Rank::Seven => 7,
+            Rank::Eight => 8,
+            Rank::Nine => 9,
+            Rank::Ten => 10,
+            Rank::Jack => 11,
+            Rank::Queen => 12,
+            Rank::King => 13,
+            Rank::Ace => 14,
+        }
+    }
+
+    //Inverse of value().
+    pub(crate) fn from_u8(value: u8) -> Option<Rank> {
+        match value {
+            2 => Some(Rank::Two),
+            3 => Some(Rank::Three),
+            4 => Some(Rank::Four),
+            5 => Some(Rank::Five),
+            6 => Some(Rank::Six),
+            7 => Some(Rank::Seven),
+            8 => Some(Rank::Eight),
+            9 => Some(Rank::Nine),
+            10 => Some(Rank::Ten),
+            11 => Some(Rank::Jack),
+            12 => Some(Rank::Queen),
+            13 => Some(Rank::King),
+            14 => Some(Rank::Ace),
+            _ => None,
+        }
+    }
+
+    //The symbol and name of each rank.
+    fn notations(&self) -> (&'static str, &'static str) {
+        match self {
+            Rank::Two => ("2", "Two"),
+            Rank::Three => ("3", "Three"),
+            Rank::Four => ("4", "Four"),
+            Rank::Five => ("5", "Five"),
+            Rank::Six => ("6", "Six"),
+            Rank::Seven => ("7", "Seven"),
+            Rank::Eight => ("8", "Eight"),
+            Rank::Nine => ("9", "Nine"),
+            Rank::Ten => ("T", "Ten"),
+            Rank::Jack => ("J", "Jack"),
+            Rank::Queen => ("Q", "Queen"),
+            Rank::King => ("K", "King"),
+            Rank::Ace => ("A", "Ace"),
+        }
+    }
+}
+
+/// Accepts the rank symbol in either case, with a ten written as `T` or `10`, or the rank's name
+/// (`ace`, `Ten`).
+impl FromStr for Rank {
+    type Err = CardError;
+
+    fn from_str(rank: &str) -> Result<Self, Self::Err> {
+        if rank == "10" {
+            return Ok(Rank::Ten);
+        }
+        Rank::ALL
+            .iter()
+            .copied()
+            .find(|candidate| {
+                let (symbol, name) = candidate.notations();
+                symbol.eq_ignore_ascii_case(rank) || name.eq_ignore_ascii_case(rank)
+            })
+            .ok_or_else(|| CardError::IncorrectCard { card: text(rank) })
+    }
+}
+
+//Implementing Ord/PartialOrd will be necessary to compare n, n+1 chars. This would require a
+//value hierarchy -> implement a value method for Rank
+impl PartialOrd for Rank {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rank {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.value().cmp(&other.value())
+    }
+}
+
+/// A single playing card, e.g. `Jh`, the Jack of Hearts.
+///
+/// A card is stored as its [index](Card::index) in the deck, a single byte.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Card(u8);
+
+impl Card {
+    /// The number of cards in a standard deck, one more than the highest [index](Card::index).
+    pub const COUNT: usize = 52;
+
+    /// Creates the card of the given rank and suit.
+    pub fn new(rank: Rank, suit: Suit) -> Self {
+        Self(suit.index() * RANKS + rank.value() - Rank::Two.value())
+    }
+
+    /// The rank of this card.
+    pub fn rank(&self) -> Rank {
+        Rank::from_u8(self.0 % RANKS + Rank::Two.value()).expect("a card index holds a valid rank")
+    }
+
+    /// The suit of this card.
+    pub fn suit(&self) -> Suit {
+        Suit::ALL[(self.0 / RANKS) as usize]
+    }
+
+    /// This card's position in [`Card::deck`] order, from 0 for the two of hearts to 51 for the
+    /// ace of clubs: thirteen cards to a suit, hearts, diamonds, spades and then clubs.
+    pub fn index(&self) -> u8 {
+        self.0
+    }
+
+    /// The card at the given [index](Card::index), or `None` past the end of the deck.
+    pub fn from_index(index: u8) -> Option<Self> {
+        Some(Self(index)).filter(|_| (index as usize) < Self::COUNT)
+    }
+
+    /// Every card of a standard 52 card deck, suit by suit from the two up to the ace.
+    pub fn deck() -> impl Iterator<Item = Card> {
+        Self::deck_from(Rank::Two)
+    }
+
+    /// The 36 card deck of short deck (6+) Hold'em: every card from the six up to the ace.
+    pub fn short_deck() -> impl Iterator<Item = Card> {
+        Self::deck_from(Rank::Six)
+    }
+
+    fn deck_from(lowest: Rank) -> impl Iterator<Item = Card> {
+        (0..Self::COUNT as u8)
+            .map(Card)
+            .filter(move |card| card.rank() >= lowest)
+    }
+
+    /// This card packed into 32 bits the way Cactus Kev's evaluator expects, for
+    /// [`evaluate_bits`]:
+    ///
+    /// ```text
+    /// xxxbbbbb bbbbbbbb cdhsrrrr xxpppppp
+    /// ```
+    ///
+    /// `b` has one bit set for the rank, two at the bottom up to ace at the top, `cdhs` one bit
+    /// for the suit, `r` the rank from 0 for a two up to 12 for an ace, and `p` the rank's prime,
+    /// from 2 for a two up to 41 for an ace.
+    ///
+    /// [`evaluate_bits`]: crate::evaluate_bits
+    pub fn to_bits(&self) -> u32 {
+        let rank = (self.0 % RANKS) as u32;
+        let suit = match self.suit() {
+            Suit::Spades => 0x1000,
+            Suit::Hearts => 0x2000,
+            Suit::Diamonds => 0x4000,
+            Suit::Clubs => 0x8000,
+        };
+        (1 << (16 + rank)) | suit | (rank << 8) | PRIMES[rank as usize]
+    }
+
+    /// Writes this card out in the given notation. See [`CardFormat`].
+    pub fn display(&self, format: CardFormat) -> Formatted<'_, Self> {
+        Formatted {
+            value: self,
+            format,
+        }
+    }
+
+    //The length in bytes of the first card in a run of compact cards such as "10hKd": a rank,
+    //which is two chars only for "10", followed by a single suit char.
+    pub(crate) fn first_len(cards: &str) -> usize {
+        let rank = if cards.starts_with("10") {
+            2
+        } else {
+            cards.chars().next().map_or(0, char::len_utf8)
+        };
+        rank + cards[rank..].chars().next().map_or(0, char::len_utf8)
+    }
+}
+
+/// Parses a rank followed by a suit, such as `Ah`, `10H`, `td` or `Q♠`, or the long form
+/// `Queen of Spades`.
+impl FromStr for Card {
+    type Err = CardError;
+
+    //The suit is always the last char, so split there rather than by byte length, which
+    //would panic on multi-byte input.
+    fn from_str(card: &str) -> Result<Self, Self::Err> {
+        //" of " is all ASCII, so wherever it matches is a char boundary.
+        if let Some(split) = card
+            .as_bytes()
+            .windows(4)
+            .position(|window| window.eq_ignore_ascii_case(b" of "))
+        {
+            return Ok(Self::new(
+                card[..split].trim().parse()?,
+                card[split + 4..].trim().parse()?,
+            ));
+        }
+
+        //A token with no suit, a lone char such as "1" or a whole rank such as "10", is reported in
+        //full rather than split before its last char.
+        let whole = || CardError::IncorrectCard { card: text(card) };
+        let (split, suit) = card
+            .char_indices()
+            .last()
+            .filter(|&(split, _)| split > 0)
+            .ok_or_else(whole)?;
+        if card.parse::<Rank>().is_ok() {
+            return Err(whole());
+        }
+        Ok(Self::new(card[..split].parse()?, Suit::try_from(suit)?))
+    }
+}
+
+//Written as the rank and suit rather than the index, which means little on its own.
+impl fmt::Debug for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Card")
+            .field("rank", &self.rank())
+            .field("suit", &self.suit())
+            .finish()
+    }
+}
+
+impl From<Card> for u8 {
+    fn from(card: Card) -> Self {
+        card.index()
+    }
+}
+
+/// Fails with [`CardError::IncorrectCard`] for an index past the end of the deck.
+impl TryFrom<u8> for Card {
+    type Error = CardError;
+
+    fn try_from(index: u8) -> Result<Self, Self::Error> {
+        Card::from_index(index).ok_or_else(|| CardError::IncorrectCard { card: text(index) })
+    }
+}
+
+impl TryFrom<&str> for Card {
+    type Error = CardError;
+
+    fn try_from(card: &str) -> Result<Self, Self::Error> {
+        card.parse()
+    }
+}
+
+/// How cards are written out by [`Card::display`] and [`Hand::display`].
+///
+/// [`Hand::display`]: crate::Hand::display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CardFormat {
+    /// Rank symbol and lower case suit letter, e.g. `Ah` or `Td`. Used by `{}`.
+    #[default]
+    Short,
+    /// Rank and suit names, e.g. `Ace of Hearts`. Used by the alternate form `{:#}`.
+    Long,
+    /// Rank symbol and suit symbol, e.g. `A♥`.
+    Unicode,
+}
+
+/// A value paired with the [`CardFormat`] it should be displayed in.
+#[derive(Debug, Clone, Copy)]
+pub struct Formatted<'a, T: ?Sized> {
+    pub(crate) value: &'a T,
+    pub(crate) format: CardFormat,
+}
+
+impl fmt::Display for Formatted<'_, Card> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (rank, rank_name) = self.value.rank().notations();
+        let (letter, suit_name, symbol) = self.value.suit().notations();
+        match self.format {
+            CardFormat::Short => write!(f, "{}{}", rank, letter),
+            CardFormat::Long => write!(f, "{} of {}", rank_name, suit_name),
+            CardFormat::Unicode => write!(f, "{}{}", rank, symbol),
+        }
+    }
+}
+
+//Short by default, long with {:#}. Both parse back with FromStr.
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = if f.alternate() {
+            CardFormat::Long
+        } else {
+            CardFormat::Short
+        };
+        self.display(format).fmt(f)
+    }
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (symbol, name) = self.notations();
+        f.write_str(if f.alternate() { name } else { symbol })
+    }
+}
+
+impl fmt::Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (letter, name, _) = self.notations();
+        if f.alternate() {
+            f.write_str(name)
+        } else {
+            write!(f, "{}", letter)
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use core::fmt;
+use core::iter::FromIterator;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not, Sub, SubAssign};
+
+use crate::card::{Card, Rank, Suit};
+
+/// A set of cards from a standard deck, held as one bit per card.
+///
+/// Bit `i` stands for the card with [index](Card::index) `i`, so every set operation is a single
+/// instruction and a set is as cheap to copy as an integer. Iteration goes in [`Card::deck`]
+/// order. `|`, `&`, `-` and `!` stand for union, intersection, difference and complement.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CardSet(u64);
+
+//The thirteen cards of the first suit; the others follow thirteen bits apart.
+const SUIT_MASK: u64 = (1 << 13) - 1;
+
+impl CardSet {
+    /// The set holding no cards.
+    pub const EMPTY: CardSet = CardSet(0);
+    /// The set holding all 52 cards.
+    pub const FULL: CardSet = CardSet((1 << Card::COUNT) - 1);
+
+    /// The set with the bits of the given value, bit `i` for the card with index `i`. Bits
+    /// above the 52nd are dropped.
+    pub fn from_bits(bits: u64) -> Self {
+        Self(bits & Self::FULL.0)
+    }
+
+    /// The bits of this set, bit `i` for the card with index `i`.
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+
+    /// Every card of the given suit.
+    pub fn of_suit(suit: Suit) -> Self {
+        Self(SUIT_MASK << (suit.index() as u64 * 13))
+    }
+
+    /// Every card of the given rank.
+    pub fn of_rank(rank: Rank) -> Self {
+        Self(Suit::ALL.iter().fold(0, |bits, &suit| {
+            bits | 1u64 << Card::new(rank, suit).index()
+        }))
+    }
+
+    /// The ranks held in the given suit as thirteen bits, the two at the bottom and the ace at
+    /// the top, as used to find flushes and straights.
+    pub fn suit_ranks(&self, suit: Suit) -> u16 {
+        ((self.0 >> (suit.index() as u64 * 13)) & SUIT_MASK) as u16
+    }
+
+    /// Whether the card is in the set.
+    pub fn contains(&self, card: Card) -> bool {
+        self.0 & Self::from(card).0 != 0
+    }
+
+    /// Adds a card, returning whether it was not already in the set.
+    pub fn insert(&mut self, card: Card) -> bool {
+        let added = !self.contains(card);
+        self.0 |= Self::from(card).0;
+        added
+    }
+
+    /// Takes a card out, returning whether it was in the set.
+    pub fn remove(&mut self, card: Card) -> bool {
+        let removed = self.contains(card);
+        self.0 &= !Self::from(card).0;
+        removed
+    }
+
+    /// The cards in either set.
+    pub fn union(&self, other: CardSet) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// The cards in both sets.
+    pub fn intersection(&self, other: CardSet) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// The cards in this set but not the other.
+    pub fn difference(&self, other: CardSet) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Whether the sets have no card in common.
+    pub fn is_disjoint(&self, other: CardSet) -> bool {
+        self.0 & other.0 == 0
+    }
+
+    /// The number of cards in the set.
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Whether the set holds no cards.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The cards in the set, in [`Card::deck`] order.
+    pub fn iter(&self) -> CardSetIter {
+        CardSetIter(self.0)
+    }
+}
+
+/// The cards of a [`CardSet`], in [`Card::deck`] order.
+#[derive(Debug, Clone)]
+pub struct CardSetIter(u64);
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = self.0.trailing_zeros() as u8;
+        //Clear the lowest set bit.
+        self.0 &= self.0 - 1;
+        Card::from_index(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for CardSetIter {}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = CardSetIter;
+
+    fn into_iter(self) -> CardSetIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &CardSet {
+    type Item = Card;
+    type IntoIter = CardSetIter;
+
+    fn into_iter(self) -> CardSetIter {
+        self.iter()
+    }
+}
+
+impl From<Card> for CardSet {
+    fn from(card: Card) -> Self {
+        Self(1 << card.index())
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(cards: I) -> Self {
+        let mut set = Self::EMPTY;
+        set.extend(cards);
+        set
+    }
+}
+
+impl<'a> FromIterator<&'a Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = &'a Card>>(cards: I) -> Self {
+        cards.into_iter().copied().collect()
+    }
+}
+
+impl Extend<Card> for CardSet {
+    fn extend<I: IntoIterator<Item = Card>>(&mut self, cards: I) {
+        for card in cards {
+            self.insert(card);
+        }
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+impl BitOrAssign for CardSet {
+    fn bitor_assign(&mut self, other: Self) {
+        *self = self.union(other);
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        self.intersection(other)
+    }
+}
+
+impl BitAndAssign for CardSet {
+    fn bitand_assign(&mut self, other: Self) {
+        *self = self.intersection(other);
+    }
+}
+
+impl Sub for CardSet {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.difference(other)
+    }
+}
+
+impl SubAssign for CardSet {
+    fn sub_assign(&mut self, other: Self) {
+        *self = self.difference(other);
+    }
+}
+
+//The complement within the deck, never setting the unused high bits.
+impl Not for CardSet {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::FULL.difference(self)
+    }
+}
+
+//Cards separated by spaces, e.g. "2h Ah Kd".
+impl fmt::Display for CardSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, card) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            card.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for CardSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
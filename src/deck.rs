@@ -0,0 +1,151 @@
+use std::fmt::Display;
+
+use crate::card::Card;
+use crate::error::{text, CardError};
+use crate::rng::Rng;
+use crate::wild::WildCard;
+
+/// A deck of cards to shuffle and deal from.
+///
+/// Cards are dealt from the top. Dealt and burned cards, as well as cards taken out with
+/// [`Deck::remove`], are gone from the deck; [`Deck::remaining`] shows what is left, top first.
+///
+/// Shuffling is driven by a seed, and the same seed always gives the same order, on every
+/// platform and in every release of this crate, so a deal can be replayed from its seed alone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deck<C = Card> {
+    //The top of the deck is the end of the vector, so dealing pops.
+    cards: Vec<C>,
+}
+
+impl Deck<Card> {
+    /// The standard 52 card deck, in [`Card::deck`] order with the two of hearts on top.
+    pub fn standard() -> Self {
+        Self::new(Card::deck().collect())
+    }
+
+    /// The 36 card short deck, six up to ace. See [`Card::short_deck`].
+    pub fn short() -> Self {
+        Self::new(Card::short_deck().collect())
+    }
+
+    /// Takes cards which are already known to be out of play, such as another player's exposed
+    /// hole cards, out of the deck.
+    ///
+    /// Fails with [`CardError::NotInDeck`] on the first card which is not in the deck; the cards
+    /// before it have been removed by then. Where a deck holds copies of a card, each copy
+    /// removed takes out one.
+    pub fn remove(&mut self, cards: &[Card]) -> Result<(), CardError> {
+        self.remove_by(cards, |card| card.index() as usize)
+    }
+}
+
+impl Deck<WildCard> {
+    /// The standard 52 card deck with the given number of jokers added at the bottom.
+    pub fn with_jokers(jokers: usize) -> Self {
+        Self::new(
+            Card::deck()
+                .map(WildCard::Natural)
+                .chain(std::iter::repeat_n(WildCard::Joker, jokers))
+                .collect(),
+        )
+    }
+
+    /// Takes cards which are already known to be out of play out of the deck, failing as
+    /// [`Deck<Card>::remove`] does. Each joker removed takes out one of the deck's jokers.
+    pub fn remove(&mut self, cards: &[WildCard]) -> Result<(), CardError> {
+        self.remove_by(cards, |card| match card {
+            WildCard::Natural(card) => card.index() as usize,
+            WildCard::Joker => 52,
+        })
+    }
+}
+
+impl<C: Copy + PartialEq + Display> Deck<C> {
+    /// A deck holding the given cards, the first card on top.
+    pub fn new(cards: Vec<C>) -> Self {
+        let mut cards = cards;
+        cards.reverse();
+        Self { cards }
+    }
+
+    /// Shuffles the cards left in the deck. The order depends only on the seed and the cards
+    /// left, so shuffling equal decks with equal seeds always gives equal decks.
+    pub fn shuffle(&mut self, seed: u64) {
+        let mut rng = Rng::new(seed);
+        //Fisher-Yates, from the bottom of the deck up.
+        for i in (1..self.cards.len()).rev() {
+            self.cards.swap(i, rng.below(i + 1));
+        }
+    }
+
+    /// Deals `n` cards from the top of the deck, in the order they come off it.
+    ///
+    /// Fails with [`CardError::NotEnoughCards`], dealing nothing, if fewer than `n` cards remain.
+    pub fn deal(&mut self, n: usize) -> Result<Vec<C>, CardError> {
+        if n > self.cards.len() {
+            return Err(CardError::NotEnoughCards {
+                requested: n,
+                remaining: self.cards.len(),
+            });
+        }
+        let mut dealt = self.cards.split_off(self.cards.len() - n);
+        dealt.reverse();
+        Ok(dealt)
+    }
+
+    /// Burns the top card: it is taken off the deck and returned, but not dealt to anyone.
+    pub fn burn(&mut self) -> Result<C, CardError> {
+        self.cards.pop().ok_or(CardError::NotEnoughCards {
+            requested: 1,
+            remaining: 0,
+        })
+    }
+
+    //Deck::remove, with every card given a slot by `slot`: below 52 for a natural card, by its
+    //Card::index, and 52 for a joker. The cards asked for are counted off against what the deck
+    //holds, slot by slot, and then all taken out in one pass, copies from the bottom up. Counts
+    //rather than a CardSet, since a deck may hold copies of a card.
+    fn remove_by(&mut self, cards: &[C], slot: fn(C) -> usize) -> Result<(), CardError> {
+        let mut held = [0usize; 53];
+        for &card in &self.cards {
+            held[slot(card)] += 1;
+        }
+        let mut taken = [0usize; 53];
+        let mut missing = None;
+        for &card in cards {
+            let slot = slot(card);
+            if taken[slot] == held[slot] {
+                missing = Some(card);
+                break;
+            }
+            taken[slot] += 1;
+        }
+        self.cards.retain(|&card| {
+            let taken = &mut taken[slot(card)];
+            *taken == 0 || {
+                *taken -= 1;
+                false
+            }
+        });
+        match missing {
+            Some(card) => Err(CardError::NotInDeck { card: text(card) }),
+            None => Ok(()),
+        }
+    }
+
+    /// The cards left in the deck, top first.
+    pub fn remaining(&self) -> impl ExactSizeIterator<Item = C> + '_ {
+        self.cards.iter().rev().copied()
+    }
+
+    /// The number of cards left in the deck.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Whether every card has been dealt, burned or removed.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+}
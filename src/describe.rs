@@ -0,0 +1,200 @@
+use std::fmt;
+
+use crate::card::{Card, Rank};
+use crate::error::PokerError;
+use crate::hand::{parse_showdown, Hand};
+use crate::hand_rank::{best_indices, evaluate, HandRank};
+use crate::ranking::Ranking;
+
+/// A hand put into words, e.g. "Two Pair, Kings and Fives, Ace kicker".
+///
+/// The ranks are the [tie-break ranks](HandRank::tie_break) of the hand's [`HandRank`], in the
+/// same order: comparing two descriptions of the same category rank by rank settles the hands
+/// exactly as their ranks do.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HandDescription {
+    pub ranking: Ranking,
+    /// The ranks which make the category: the rank of the pair or the set, both pairs, the three
+    /// and the two of a full house, or the highest card of a straight, flush or high card hand.
+    pub made: Vec<Rank>,
+    /// The other cards which count, highest first. Straights have none.
+    pub kickers: Vec<Rank>,
+}
+
+impl HandDescription {
+    /// Describes a five card hand. The cards may be in any order.
+    pub fn of(cards: &[Card]) -> Self {
+        Self::of_rank(evaluate(cards))
+    }
+
+    //Describes the hand a HandRank stands for, made ranks first and then the kickers.
+    pub(crate) fn of_rank(rank: HandRank) -> Self {
+        let ranking = rank.ranking();
+        let made = match ranking {
+            Ranking::FullHouse | Ranking::TwoPair => 2,
+            _ => 1,
+        };
+        let mut ranks = rank.tie_break();
+        Self {
+            ranking,
+            made: ranks.by_ref().take(made).collect(),
+            kickers: ranks.collect(),
+        }
+    }
+
+    //The part of the description which names the made ranks, e.g. "Kings full of Fives".
+    fn made(&self) -> String {
+        let plural = |rank: &Rank| match rank {
+            Rank::Six => "Sixes".to_string(),
+            rank => format!("{:#}s", rank),
+        };
+        match (self.ranking, &self.made[..]) {
+            (Ranking::FullHouse, [three, two]) => {
+                format!("{} full of {}", plural(three), plural(two))
+            }
+            (Ranking::TwoPair, [high, low]) => format!("{} and {}", plural(high), plural(low)),
+            (Ranking::Straight | Ranking::StraightFlush | Ranking::Flush, [high]) => {
+                format!("{:#} high", high)
+            }
+            (Ranking::HighCard, [high]) => format!("{:#}", high),
+            (_, made) => made.iter().map(plural).collect::<Vec<_>>().join(" and "),
+        }
+    }
+}
+
+//"Two Pair, Kings and Fives, Ace kicker", or "..., Ace, Queen, Four kickers" with more than one.
+impl fmt::Display for HandDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}", self.ranking, self.made())?;
+        if !self.kickers.is_empty() {
+            let kickers: Vec<String> = self
+                .kickers
+                .iter()
+                .map(|rank| format!("{:#}", rank))
+                .collect();
+            let plural = if kickers.len() == 1 { "" } else { "s" };
+            write!(f, ", {} kicker{}", kickers.join(", "), plural)?;
+        }
+        Ok(())
+    }
+}
+
+/// What settled a showdown, as reported by [`explain_winners`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Decider {
+    /// There was only one hand.
+    Uncontested,
+    /// The winning hands could not be told apart, so the pot is split.
+    Split,
+    /// The winner's category beat the runner-up's.
+    Ranking,
+    /// Both hands fell into the same category, and the winner's made ranks were higher, as with
+    /// a higher pair or a higher straight.
+    Made,
+    /// Both hands made the same thing, and the first kicker which differed settled it.
+    Kicker { winner: Rank, runner_up: Rank },
+}
+
+/// Why a showdown went the way it did: every hand described, who won, and what decided it
+/// against the best of the losing hands.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Explanation {
+    /// A description of every hand, in the order they were given.
+    pub hands: Vec<HandDescription>,
+    /// The indices of the winning hands.
+    pub winners: Vec<usize>,
+    /// The index of the best losing hand, the first of them if several tie. `None` when every
+    /// hand won.
+    pub runner_up: Option<usize>,
+    pub decider: Decider,
+}
+
+/// Settles a showdown as [`try_winning_hands`] does, and explains the result.
+///
+/// The explanation compares the first winner against the [runner-up](Explanation::runner_up)
+/// rank by rank, the way their [`HandRank`]s compare. Errors are reported as by
+/// [`try_winning_hands`].
+///
+/// [`try_winning_hands`]: crate::try_winning_hands
+pub fn explain_winners(hands: &[&str]) -> Result<Explanation, PokerError> {
+    let mut dealt = Vec::new();
+    parse_showdown(hands, &mut dealt)?;
+
+    let ranks: Vec<HandRank> = dealt.iter().map(Hand::rank).collect();
+    let winners = best_indices(&ranks);
+    let runner_up = (0..ranks.len())
+        .filter(|index| !winners.contains(index))
+        .max_by(|&a, &b| ranks[a].cmp(&ranks[b]).then(b.cmp(&a)));
+    let hands: Vec<HandDescription> = ranks
+        .iter()
+        .map(|&rank| HandDescription::of_rank(rank))
+        .collect();
+
+    let decider = match (winners.first(), runner_up) {
+        (Some(_), None) if hands.len() == 1 => Decider::Uncontested,
+        (Some(&winner), Some(runner_up)) if winners.len() == 1 => {
+            let (winner, runner_up) = (&hands[winner], &hands[runner_up]);
+            if winner.ranking != runner_up.ranking {
+                Decider::Ranking
+            } else if winner.made != runner_up.made {
+                Decider::Made
+            } else {
+                winner
+                    .kickers
+                    .iter()
+                    .zip(&runner_up.kickers)
+                    .find(|(a, b)| a != b)
+                    .map_or(Decider::Split, |(&winner, &runner_up)| Decider::Kicker {
+                        winner,
+                        runner_up,
+                    })
+            }
+        }
+        _ => Decider::Split,
+    };
+    Ok(Explanation {
+        hands,
+        winners,
+        runner_up,
+        decider,
+    })
+}
+
+//"both Two Pair, Kings and Fives; won on kicker A vs Q".
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let winner = match self.winners.first() {
+            Some(&winner) => &self.hands[winner],
+            None => return f.write_str("no hands"),
+        };
+        let runner_up = self.runner_up.map(|index| &self.hands[index]);
+        match (self.decider, runner_up) {
+            (Decider::Ranking, Some(runner_up)) => write!(f, "{} beats {}", winner, runner_up),
+            (Decider::Made, Some(runner_up)) => write!(
+                f,
+                "both {}; {} beats {}",
+                winner.ranking,
+                winner.made(),
+                runner_up.made()
+            ),
+            (
+                Decider::Kicker {
+                    winner: a,
+                    runner_up: b,
+                },
+                _,
+            ) => write!(
+                f,
+                "both {}, {}; won on kicker {} vs {}",
+                winner.ranking,
+                winner.made(),
+                a,
+                b
+            ),
+            (Decider::Split, _) if self.winners.len() > 1 => {
+                write!(f, "split pot, {} hands with {}", self.winners.len(), winner)
+            }
+            _ => write!(f, "{} wins uncontested", winner),
+        }
+    }
+}
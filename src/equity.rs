@@ -0,0 +1,308 @@
+use crate::batch::simulate;
+use crate::card::{Card, Rank, Suit};
+use crate::card_set::CardSet;
+use crate::error::{CardError, PokerError};
+use crate::hand::{check_deal, check_size};
+use crate::hand_rank::{best_indices, HandRank};
+use crate::holdem::{HoleCards, BOARD_SIZE};
+use crate::seven::evaluate_seven;
+
+/// How one player fared over the boards dealt by [`equity`] or [`exact_equity`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EquityResult {
+    /// The boards this player won outright.
+    pub wins: u64,
+    /// The boards on which this player split the pot with others.
+    pub ties: u64,
+    /// The boards dealt.
+    pub samples: u64,
+    /// Whether every possible board was dealt exactly once, so the figures are exact rather than
+    /// estimates.
+    pub exhaustive: bool,
+    //This player's share of the pot summed over every board, counted in units of 1/pot_unit of
+    //a pot so that split pots stay exact. The sum of squared shares is for the standard error.
+    pot_shares: u64,
+    pot_unit: u64,
+    squares: f64,
+}
+
+impl EquityResult {
+    fn new(pot_unit: u64, exhaustive: bool) -> Self {
+        Self {
+            pot_unit,
+            exhaustive,
+            ..Self::default()
+        }
+    }
+
+    fn record(&mut self, units: u64) {
+        self.samples += 1;
+        if units == self.pot_unit {
+            self.wins += 1;
+        } else if units > 0 {
+            self.ties += 1;
+        }
+        self.pot_shares += units;
+        let share = units as f64 / self.pot_unit as f64;
+        self.squares += share * share;
+    }
+
+    //Adds in the boards another run of the same showdown dealt.
+    fn absorb(&mut self, other: EquityResult) {
+        self.wins += other.wins;
+        self.ties += other.ties;
+        self.samples += other.samples;
+        self.pot_shares += other.pot_shares;
+        self.squares += other.squares;
+    }
+
+    fn rate(&self, count: f64) -> f64 {
+        if self.samples == 0 {
+            0.0
+        } else {
+            count / self.samples as f64
+        }
+    }
+
+    /// The fraction of boards won outright.
+    pub fn win(&self) -> f64 {
+        self.rate(self.wins as f64)
+    }
+
+    /// The fraction of boards on which the pot was split.
+    pub fn tie(&self) -> f64 {
+        self.rate(self.ties as f64)
+    }
+
+    /// The share of the pot this player can expect: every win counts in full, and every split
+    /// pot counts for the part of it this player takes.
+    pub fn equity(&self) -> f64 {
+        let (numerator, denominator) = self.pot_share();
+        if denominator == 0 {
+            0.0
+        } else {
+            numerator as f64 / denominator as f64
+        }
+    }
+
+    /// [`EquityResult::equity`] as an exact fraction, numerator first. It is not reduced to
+    /// lowest terms.
+    pub fn pot_share(&self) -> (u64, u64) {
+        (self.pot_shares, self.samples * self.pot_unit)
+    }
+
+    /// The standard error of [`EquityResult::equity`]. Sampling until it falls below the
+    /// precision wanted is a good stopping rule; quadrupling the samples halves it. Exhaustive
+    /// results have no error.
+    pub fn std_error(&self) -> f64 {
+        if self.exhaustive || self.samples < 2 {
+            return 0.0;
+        }
+        let n = self.samples as f64;
+        let mean = self.equity();
+        let variance = (self.squares / n - mean * mean).max(0.0) * n / (n - 1.0);
+        (variance / n).sqrt()
+    }
+}
+
+/// Estimates each Texas Hold'em player's chances by dealing the rest of the board at random.
+///
+/// `board` holds the community cards known so far (none preflop, three on the flop and so on),
+/// and `dead` any other cards known to be out of play, such as folded or burned cards. Every
+/// sample deals the missing board cards from what is left of the deck and settles the showdown
+/// as [`holdem_winners`] would. The same seed always deals the same boards. With the `parallel`
+/// feature the samples are spread across threads in chunks, each dealt from its own stream of
+/// the seed, so the boards and the results do not depend on the number of threads.
+///
+/// Fails if the board holds more than five cards or a card is known twice. Errors carry the
+/// player's index, `players.len()` for the board or `players.len() + 1` for the dead cards. Fails
+/// with [`CardError::NotEnoughCards`], against the dead cards, if too few cards are left unknown
+/// to complete the board.
+///
+/// [`holdem_winners`]: crate::holdem_winners
+pub fn equity(
+    players: &[HoleCards],
+    board: &[Card],
+    dead: &[Card],
+    iterations: u64,
+    seed: u64,
+) -> Result<Vec<EquityResult>, PokerError> {
+    let showdown = Showdown::new(players, board, dead)?;
+    let chunks = simulate(iterations, seed, |rng, samples| {
+        let mut showdown = showdown.clone();
+        let mut results = vec![EquityResult::new(showdown.pot_unit, false); players.len()];
+        let missing = showdown.missing();
+        let deck_len = showdown.deck.len();
+        for _ in 0..samples {
+            //A partial Fisher-Yates shuffle: only the cards about to be dealt need to be random.
+            for i in 0..missing {
+                showdown.deck.swap(i, i + rng.below(deck_len - i));
+            }
+            let indices = [0, 1, 2, 3, 4];
+            showdown.settle(&indices[..missing], &mut results);
+        }
+        results
+    });
+    let mut results = vec![EquityResult::new(showdown.pot_unit, false); players.len()];
+    for chunk in chunks {
+        for (result, part) in results.iter_mut().zip(chunk) {
+            result.absorb(part);
+        }
+    }
+    Ok(results)
+}
+
+/// Works out each Texas Hold'em player's chances exactly, by dealing every possible rest of the
+/// board once.
+///
+/// Takes the same arguments as [`equity`], bar the sampling, and reports the same way, except that
+/// the results are [exhaustive](EquityResult::exhaustive). The work grows with the number of
+/// boards: 990 on the flop and 44 on the turn, but 1,712,304 heads-up preflop. See
+/// [`equity_auto`] to fall back to sampling when there are too many.
+pub fn exact_equity(
+    players: &[HoleCards],
+    board: &[Card],
+    dead: &[Card],
+) -> Result<Vec<EquityResult>, PokerError> {
+    let mut showdown = Showdown::new(players, board, dead)?;
+    let mut results = vec![EquityResult::new(showdown.pot_unit, true); players.len()];
+    //Every combination of the missing cards, as increasing indices into the deck.
+    let missing = showdown.missing();
+    let deck_len = showdown.deck.len();
+    let mut indices = [0, 1, 2, 3, 4];
+    loop {
+        showdown.settle(&indices[..missing], &mut results);
+        match (0..missing)
+            .rev()
+            .find(|&i| indices[i] < deck_len - missing + i)
+        {
+            Some(i) => {
+                indices[i] += 1;
+                for j in i + 1..missing {
+                    indices[j] = indices[j - 1] + 1;
+                }
+            }
+            None => return Ok(results),
+        }
+    }
+}
+
+/// The number of boards [`exact_equity`] would deal, i.e. the ways of choosing the missing board
+/// cards from the cards which are not known.
+pub fn remaining_boards(players: usize, board: usize, dead: usize) -> u64 {
+    let unknown = 52usize.saturating_sub(2 * players + board + dead) as u64;
+    let missing = BOARD_SIZE.saturating_sub(board) as u64;
+    (0..missing).fold(1, |boards, i| boards * unknown.saturating_sub(i) / (i + 1))
+}
+
+/// [`exact_equity`] when there are no more than `iterations` boards to deal, and [`equity`] with
+/// that many samples otherwise. Check [`EquityResult::exhaustive`] to see which it was.
+pub fn equity_auto(
+    players: &[HoleCards],
+    board: &[Card],
+    dead: &[Card],
+    iterations: u64,
+    seed: u64,
+) -> Result<Vec<EquityResult>, PokerError> {
+    if remaining_boards(players.len(), board.len(), dead.len()) <= iterations {
+        exact_equity(players, board, dead)
+    } else {
+        equity(players, board, dead, iterations, seed)
+    }
+}
+
+//The known cards of a hand in progress, and what is needed to settle it on any run-out.
+#[derive(Clone)]
+pub(crate) struct Showdown<'a> {
+    players: &'a [HoleCards],
+    //The board, with the missing cards filled in by winners.
+    board: [Card; BOARD_SIZE],
+    known: usize,
+    //Every card which is not known.
+    pub(crate) deck: Vec<Card>,
+    //The least common multiple of 1..=players, so that a pot split any number of ways is a
+    //whole number of units.
+    pot_unit: u64,
+    ranks: Vec<HandRank>,
+}
+
+impl<'a> Showdown<'a> {
+    pub(crate) fn new(
+        players: &'a [HoleCards],
+        board: &[Card],
+        dead: &[Card],
+    ) -> Result<Self, PokerError> {
+        check_size(board, 0..=BOARD_SIZE).map_err(|error| error.in_hand(players.len()))?;
+        check_deal(
+            players
+                .iter()
+                .map(|hole| &hole[..])
+                .chain(std::iter::once(board))
+                .chain(std::iter::once(dead)),
+        )?;
+
+        let known: CardSet = players.iter().flatten().chain(board).chain(dead).collect();
+        //Too many cards out of play leave too few to finish the board with.
+        let missing = BOARD_SIZE - board.len();
+        if CardSet::FULL.len() - known.len() < missing {
+            return Err(PokerError {
+                hand: players.len() + 1,
+                position: 0,
+                source: CardError::NotEnoughCards {
+                    requested: missing,
+                    remaining: CardSet::FULL.len() - known.len(),
+                },
+            });
+        }
+        let mut full_board = [Card::new(Rank::Two, Suit::Hearts); BOARD_SIZE];
+        full_board[..board.len()].copy_from_slice(board);
+        let pot_unit = (1..=players.len() as u64).fold(1, |lcm, n| lcm / gcd(lcm, n) * n);
+        Ok(Self {
+            players,
+            board: full_board,
+            known: board.len(),
+            deck: (!known).iter().collect(),
+            pot_unit,
+            ranks: Vec::with_capacity(players.len()),
+        })
+    }
+
+    pub(crate) fn missing(&self) -> usize {
+        BOARD_SIZE - self.known
+    }
+
+    //Completes the board with the deck cards at the given indices and returns who wins it. The
+    //winners are picked exactly as winning_hands picks them: every hand sharing the greatest
+    //HandRank.
+    pub(crate) fn winners(&mut self, indices: &[usize]) -> Vec<usize> {
+        for (slot, &index) in self.board[self.known..].iter_mut().zip(indices) {
+            *slot = self.deck[index];
+        }
+        let board = &self.board;
+        self.ranks.clear();
+        self.ranks.extend(self.players.iter().map(|hole| {
+            let mut cards = [hole[0]; 7];
+            cards[..2].copy_from_slice(hole);
+            cards[2..].copy_from_slice(board);
+            evaluate_seven(&cards)
+        }));
+        best_indices(&self.ranks)
+    }
+
+    //Deals the board as winners does and records the result for every player.
+    fn settle(&mut self, indices: &[usize], results: &mut [EquityResult]) {
+        let winners = self.winners(indices);
+        let units = self.pot_unit / winners.len().max(1) as u64;
+        for (player, result) in results.iter_mut().enumerate() {
+            result.record(if winners.contains(&player) { units } else { 0 });
+        }
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
@@ -0,0 +1,129 @@
+use core::fmt;
+
+use thiserror::Error;
+
+use crate::card::Card;
+
+//The text of any value, for quoting in an error.
+pub(crate) fn text(value: impl fmt::Display) -> ErrorText {
+    use core::fmt::Write;
+
+    let mut text = ErrorText::default();
+    //Writing never fails; anything which does not fit is dropped.
+    let _ = write!(text, "{}", value);
+    text
+}
+
+/// A card which could not be parsed, a deal which is impossible with a single deck, or a request a
+/// `Deck` cannot meet.
+//Parsing a suit may result in an error of incorrect chars are parsed.
+//The position of the offending card is attached by PokerError below.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum CardError {
+    #[error(
+        "{suit} does not translate to a correct suit. The only applicable suits are H, D, S, C or ♥, ♦, ♠, ♣"
+    )]
+    IncorrectSuit { suit: char },
+    #[error(
+        "{card} does not translate to a correct card. The only applicable cards span from 2-10 (or T) and include J, Q, K, A."
+    )]
+    IncorrectCard { card: ErrorText },
+    #[error("a hand holds exactly {expected} cards, but {found} were given")]
+    WrongHandSize { expected: usize, found: usize },
+    #[error("{card} appears twice in the same hand, first at card {first}")]
+    DuplicateCard { card: Card, first: usize },
+    #[error("{card} was already dealt to hand {hand}, card {position}")]
+    CardSharedBetweenHands {
+        card: Card,
+        hand: usize,
+        position: usize,
+    },
+    #[error(
+        "{item} is not a valid range item. Items look like QQ+, AKs, AKo, A5s-A2s, 76s+ or AhKh, optionally weighted as in AKs:0.5"
+    )]
+    IncorrectRange { item: ErrorText },
+    #[error("no combination of this range can be dealt alongside the board and the other ranges")]
+    NoCombination,
+    #[error("{card} is not in the deck")]
+    NotInDeck { card: ErrorText },
+    #[error("{requested} cards were asked for, but only {remaining} remain in the deck")]
+    NotEnoughCards { requested: usize, remaining: usize },
+}
+
+/// A [`CardError`] together with where it occurred.
+//A CardError on its own does not say where it came from. When a batch of hands is parsed,
+//the hand index and the card position within that hand (both zero based) are carried along,
+//so a single bad row can be rejected without taking down the rest.
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("hand {hand}, card {position}: {source}")]
+pub struct PokerError {
+    /// Index of the offending hand within the batch.
+    pub hand: usize,
+    /// Position of the offending card within its hand. For [`CardError::WrongHandSize`] this is
+    /// the position at which the hand fell short or overflowed, i.e. the smaller of the number of
+    /// cards given and the number expected.
+    pub position: usize,
+    #[source]
+    pub source: CardError,
+}
+
+impl PokerError {
+    /// Re-tags the error with the index of the hand it came from.
+    //Hand::parse only sees a single hand, so the caller fills in the real index.
+    pub fn in_hand(self, hand: usize) -> Self {
+        Self { hand, ..self }
+    }
+}
+
+/// The text quoted by a [`CardError`], such as the card which failed to parse.
+///
+/// The text is kept inline, so that errors need no allocation with or without the `std` feature,
+/// and anything past the first [`ErrorText::CAPACITY`] bytes is cut off.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ErrorText {
+    bytes: [u8; ErrorText::CAPACITY],
+    len: usize,
+}
+
+impl ErrorText {
+    /// The most bytes of text kept.
+    pub const CAPACITY: usize = 24;
+
+    /// The text, possibly cut short.
+    pub fn as_str(&self) -> &str {
+        //Only whole chars are ever written, so this cannot fail.
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl From<&str> for ErrorText {
+    fn from(value: &str) -> Self {
+        text(value)
+    }
+}
+
+impl fmt::Write for ErrorText {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        for c in text.chars() {
+            let end = self.len + c.len_utf8();
+            if end > Self::CAPACITY {
+                break;
+            }
+            c.encode_utf8(&mut self.bytes[self.len..end]);
+            self.len = end;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ErrorText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for ErrorText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
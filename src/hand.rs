@@ -1,135 +1,298 @@
-use thiserror::Error;
-
-//Implementing types for different suits will allow for checking equality later
-//if necessary
-#[derive(Debug, PartialEq, Eq, Clone, Hash)]
-pub enum Suit {
-    Hearts,
-    Diamonds,
-    Spades,
-    Clubs,
-}
-
-// Each card type is necessary when checking for equality and attributing a value
-#[derive(Debug, PartialEq, Eq, Clone, Hash)]
-pub enum Card {
-    // Assume Ace -> 13 at init
-    AceHigh,
-    //Special case scenario for straight flushes. Needs to be checked
-    AceLow,
-    Two,
-    Three,
-    Four,
-    Five,
-    Six,
-    Seven,
-    Eight,
-    Nine,
-    Ten,
-    Jack,
-    Queen,
-    King,
-}
-
-impl Card {
-    pub fn value(&self) -> u8 {
-        match self {
-            Card::AceHigh => 13,
-            Card::Two => 1,
-            Card::Three => 2,
-            Card::Four => 3,
-            Card::Five => 4,
-            Card::Six => 5,
-            Card::Seven => 6,
-            Card::Eight => 7,
-            Card::Nine => 8,
-            Card::Ten => 9,
-            Card::Jack => 10,
-            Card::Queen => 11,
-            Card::King => 12,
-            Card::AceLow => 1,
+use core::cmp::Reverse;
+use core::convert::TryFrom;
+use core::fmt;
+#[cfg(feature = "std")]
+use core::ops::RangeInclusive;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::card::{Card, CardFormat, Formatted, Rank, Suit};
+#[cfg(feature = "std")]
+use crate::card_set::CardSet;
+#[cfg(feature = "std")]
+use crate::describe::HandDescription;
+use crate::error::{CardError, PokerError};
+use crate::hand_rank::{evaluate, sort_by_key, HandRank};
+use crate::ranking::Ranking;
+use crate::rules::RankingRules;
+
+/// The five cards a single player shows down, kept sorted from the highest rank to the lowest.
+///
+/// A `Hand` always holds exactly five distinct cards; every constructor rejects anything else.
+/// Cards of the same rank are kept in [`Card::index`] order, so two hands holding the same cards
+/// are equal and hash alike, whatever order the cards were given in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Hand {
+    cards: [Card; Hand::SIZE],
+}
+
+impl Hand {
+    /// The number of cards in a hand.
+    pub const SIZE: usize = 5;
+
+    /// Builds a hand from its cards, sorting them from the highest rank to the lowest.
+    ///
+    /// Fails with [`CardError::DuplicateCard`] if a card appears twice.
+    pub fn new(cards: [Card; Hand::SIZE]) -> Result<Self, PokerError> {
+        //A repeated card is reported at its second position, pointing back at the first.
+        for (position, card) in cards.iter().enumerate() {
+            if let Some(first) = cards[..position].iter().position(|other| other == card) {
+                return Err(PokerError {
+                    hand: 0,
+                    position,
+                    source: CardError::DuplicateCard { card: *card, first },
+                });
+            }
         }
+        let mut cards = cards;
+        //Same rank cards go by index, so the same five cards always make the same hand.
+        sort_by_key(&mut cards, |card| (Reverse(card.rank()), card.index()));
+        Ok(Self { cards })
+    }
+
+    /// Parses a hand such as `"4S 5S 7H 8D JC"`, `"As Kd Qh Jc Ts"` or `"A♠K♦Q♥J♣T♠"`.
+    ///
+    /// Cards may be separated by whitespace or written back to back. Each card accepts the
+    /// notations of [`Card`]'s `FromStr`, apart from the long `Ace of Spades` form. The hand must
+    /// hold exactly five distinct cards.
+    ///
+    /// Errors report hand `0`; callers parsing a batch re-tag them with [`PokerError::in_hand`].
+    pub fn parse(hand: &str) -> Result<Self, PokerError> {
+        Self::new(parse_cards(hand)?)
     }
 
-    fn from_value(value: &str) -> Result<Card, CardError> {
-        match value {
-            //Always start with AceHigh
-            "A" => Ok(Card::AceHigh),
-            "2" => Ok(Card::Two),
-            "3" => Ok(Card::Three),
-            "4" => Ok(Card::Four),
-            "5" => Ok(Card::Five),
-            "6" => Ok(Card::Six),
-            "7" => Ok(Card::Seven),
-            "8" => Ok(Card::Eight),
-            "9" => Ok(Card::Nine),
-            "10" => Ok(Card::Ten),
-            "J" => Ok(Card::Jack),
-            "Q" => Ok(Card::Queen),
-            "K" => Ok(Card::King),
-            _ => Err(CardError::IncorrectCard { card: value }),
+    /// The cards of this hand, from the highest rank to the lowest.
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Writes this hand out in the given notation, cards separated by spaces (or by commas for
+    /// [`CardFormat::Long`]).
+    pub fn display(&self, format: CardFormat) -> Formatted<'_, Self> {
+        Formatted {
+            value: self,
+            format,
         }
     }
+
+    /// The category this hand falls into.
+    pub fn ranking(&self) -> Ranking {
+        Ranking::determine_ranking(&self.cards)
+    }
+
+    /// The full strength of this hand, for comparing it against other hands.
+    pub fn rank(&self) -> HandRank {
+        evaluate(&self.cards)
+    }
+
+    /// The strength of this hand under the given rules. See [`RankingRules::evaluate`].
+    pub fn rank_with(&self, rules: RankingRules) -> HandRank {
+        rules.evaluate(&self.cards)
+    }
+
+    /// This hand in words, e.g. "Two Pair, Kings and Fives, Ace kicker".
+    #[cfg(feature = "std")]
+    pub fn describe(&self) -> HandDescription {
+        HandDescription::of_rank(self.rank())
+    }
 }
-//Implementing Ord/PartialOrd will be necessary to compare n, n+1 chars. This would require a
-//value hierarchy -> implement a value method for Card
-impl PartialOrd for Card {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.value().partial_cmp(&other.value())
+
+/// Fails with [`CardError::WrongHandSize`] unless exactly five cards are given, and with
+/// [`CardError::DuplicateCard`] if a card appears twice.
+impl TryFrom<&[Card]> for Hand {
+    type Error = PokerError;
+
+    fn try_from(cards: &[Card]) -> Result<Self, Self::Error> {
+        Self::new(to_array(cards)?)
     }
 }
 
-//Hand here is defined as a pairing, e.g JH (Jack of Hearts). This may not be the actual definition of a hand.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Hand {
-    pub suit: Suit,
-    pub card: Card,
+//Args come in as an &str, where each card is seperated by a space char or follows straight
+//on from the previous suit, therefore, for each card, construct a Card. The cards are kept in the
+//order they were written, which is the order error positions refer to.
+pub(crate) fn parse_cards(hand: &str) -> Result<[Card; Hand::SIZE], PokerError> {
+    //Every card is parsed, even past the fifth, so that a bad card is reported before the count.
+    let mut cards = [Card::new(Rank::Two, Suit::Hearts); Hand::SIZE];
+    let mut found = 0;
+    for (position, card) in tokens(hand).enumerate() {
+        let card = card.parse().map_err(|source| PokerError {
+            hand: 0,
+            position,
+            source,
+        })?;
+        if let Some(slot) = cards.get_mut(position) {
+            *slot = card;
+        }
+        found += 1;
+    }
+    if found == Hand::SIZE {
+        Ok(cards)
+    } else {
+        Err(wrong_size(found))
+    }
 }
 
-//Parsing a suit may result in an error of incorrect chars are parsed.
-//Maybe feed in a position later?
-#[derive(Error, Debug)]
-pub enum CardError<'a> {
-    #[error(
-        "{suit} does not translate to a correct suit. The only applicable suits are H, D, S, C"
-    )]
-    IncorrectSuit { suit: char },
-    #[error(
-        "{card} does not translate to a correct card. The only applicable cards span form 1-10 and include J, K, Q."
-    )]
-    IncorrectCard { card: &'a str },
+//Splits a hand into the text of its cards, e.g. "AsKd 10h" into "As", "Kd" and "10h".
+pub(crate) fn tokens(hand: &str) -> impl Iterator<Item = &str> {
+    tokens_by(hand, Card::first_len)
 }
 
-impl Hand {
-    //Mapping a char to a suit. Note that unwrap will be used.
-    fn to_suit<'a>(suit: char) -> Result<Suit, CardError<'a>> {
-        match suit.to_lowercase().to_string().as_str() {
-            "h" => Ok(Suit::Hearts),
-            "d" => Ok(Suit::Diamonds),
-            "s" => Ok(Suit::Spades),
-            "c" => Ok(Suit::Clubs),
-            _ => Err(CardError::IncorrectSuit { suit }),
+//tokens for notations other than plain cards, `first_len` giving the length of the first card
+//of a run such as "JokerAs".
+pub(crate) fn tokens_by(hand: &str, first_len: fn(&str) -> usize) -> impl Iterator<Item = &str> {
+    hand.split_whitespace().flat_map(move |mut word| {
+        core::iter::from_fn(move || {
+            let (card, rest) = word.split_at(first_len(word));
+            word = rest;
+            Some(card).filter(|card| !card.is_empty())
+        })
+    })
+}
+
+pub(crate) fn to_array<T: Copy>(cards: &[T]) -> Result<[T; Hand::SIZE], PokerError> {
+    <[T; Hand::SIZE]>::try_from(cards).map_err(|_| wrong_size(cards.len()))
+}
+
+fn wrong_size(found: usize) -> PokerError {
+    PokerError {
+        hand: 0,
+        position: found.min(Hand::SIZE),
+        source: CardError::WrongHandSize {
+            expected: Hand::SIZE,
+            found,
+        },
+    }
+}
+
+//Like to_array, for the places which take a range of sizes rather than a full hand. A count
+//outside the range is reported against the nearest bound.
+#[cfg(feature = "std")]
+pub(crate) fn check_size(cards: &[Card], sizes: RangeInclusive<usize>) -> Result<(), PokerError> {
+    let expected = cards.len().clamp(*sizes.start(), *sizes.end());
+    if cards.len() == expected {
+        return Ok(());
+    }
+    Err(PokerError {
+        hand: 0,
+        position: cards.len().min(expected),
+        source: CardError::WrongHandSize {
+            expected,
+            found: cards.len(),
+        },
+    })
+}
+
+//A single deck holds each card once, so a card may appear only once across every hand of a deal.
+//The first repeat found is reported against the occurrence before it: within the same hand that is
+//a DuplicateCard, otherwise the card was shared between two hands.
+#[cfg(feature = "std")]
+pub(crate) fn check_deal<'a>(
+    hands: impl IntoIterator<Item = &'a [Card]>,
+) -> Result<(), PokerError> {
+    check_deal_by(hands, |card| Some(*card))
+}
+
+//check_deal for hands which hold more than plain cards, such as jokers. Only what `natural`
+//turns into a Card is checked; error positions still count every entry.
+#[cfg(feature = "std")]
+pub(crate) fn check_deal_by<'a, T: 'a>(
+    hands: impl IntoIterator<Item = &'a [T]>,
+    natural: impl Fn(&T) -> Option<Card>,
+) -> Result<(), PokerError> {
+    let mut dealt: HashMap<Card, (usize, usize)> = HashMap::new();
+    for (hand, cards) in hands.into_iter().enumerate() {
+        for (position, card) in cards
+            .iter()
+            .enumerate()
+            .filter_map(|(position, card)| Some((position, natural(card)?)))
+        {
+            if let Some(&(first_hand, first)) = dealt.get(&card) {
+                let source = if first_hand == hand {
+                    CardError::DuplicateCard { card, first }
+                } else {
+                    CardError::CardSharedBetweenHands {
+                        card,
+                        hand: first_hand,
+                        position: first,
+                    }
+                };
+                return Err(PokerError {
+                    hand,
+                    position,
+                    source,
+                });
+            }
+            dealt.insert(card, (hand, position));
         }
     }
+    Ok(())
+}
 
-    //For every pairing (forgive the incorrect semantics), construct a Hand Struct
-    fn retrieve_hand(hand: &str) -> Hand {
-        let suit = hand.chars().last().unwrap();
-        let val = hand.get(..hand.len() - 1).unwrap();
-        Self {
-            card: Card::from_value(val).unwrap(),
-            suit: Self::to_suit(suit).unwrap(),
+//Parses the hands of a showdown into `parsed`, in the order they were given, reporting errors the
+//way try_winning_hands documents: every hand is parsed before the deal is checked, so the first
+//bad card comes ahead of a card dealt twice. `parsed` is cleared first, so that one buffer can be
+//reused from showdown to showdown.
+#[cfg(feature = "std")]
+pub(crate) fn parse_showdown(hands: &[&str], parsed: &mut Vec<Hand>) -> Result<(), PokerError> {
+    parsed.clear();
+    let mut known = CardSet::EMPTY;
+    for (index, hand) in hands.iter().enumerate() {
+        let cards = parse_cards(hand).map_err(|error| error.in_hand(index))?;
+        known.extend(cards);
+        //A hand holding a card twice is left out; the deal check below reports it.
+        parsed.extend(Hand::new(cards).ok());
+    }
+
+    //Counting the cards is enough to see that none was dealt twice; only a repeat needs the hands
+    //looked at again to say where it is.
+    if known.len() != Hand::SIZE * hands.len() {
+        let dealt: Vec<_> = hands.iter().flat_map(|hand| parse_cards(hand)).collect();
+        check_deal(dealt.iter().map(|cards| &cards[..]))?;
+    }
+    Ok(())
+}
+
+impl FromStr for Hand {
+    type Err = PokerError;
+
+    fn from_str(hand: &str) -> Result<Self, Self::Err> {
+        Self::parse(hand)
+    }
+}
+
+impl TryFrom<&str> for Hand {
+    type Error = PokerError;
+
+    fn try_from(hand: &str) -> Result<Self, Self::Error> {
+        Self::parse(hand)
+    }
+}
+
+impl fmt::Display for Formatted<'_, Hand> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = match self.format {
+            CardFormat::Long => ", ",
+            CardFormat::Short | CardFormat::Unicode => " ",
+        };
+        for (position, card) in self.value.cards.iter().enumerate() {
+            if position > 0 {
+                f.write_str(separator)?;
+            }
+            card.display(self.format).fmt(f)?;
         }
+        Ok(())
     }
+}
 
-    //Args come in as an $[&str], where each pairing is seperated by a space char, therefore,
-    //for each pairing, construct a Hand, such that a complete "Hand" is represented by a Vec<Hand>
-    pub fn new_sorted(hands: &str) -> Vec<Hand> {
-        let mut hands = hands
-            .split_whitespace()
-            .map(Self::retrieve_hand)
-            .collect::<Vec<Hand>>();
-        hands.sort_by(|a, b| b.card.partial_cmp(&a.card).unwrap());
-        hands
+//Short by default, long with {:#}, matching Card.
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = if f.alternate() {
+            CardFormat::Long
+        } else {
+            CardFormat::Short
+        };
+        self.display(format).fmt(f)
     }
 }
@@ -0,0 +1,358 @@
+use core::convert::TryFrom;
+
+use crate::bits::{pack, straight_high, CATEGORY_SHIFT, TIE_BREAK_SLOTS};
+use crate::card::{Card, Rank};
+use crate::ranking::Ranking;
+use crate::seven::best_seven;
+
+//The packed value is laid out in bits.rs, which build.rs shares to generate the lookup tables.
+//
+//Lowball ranks, where the smaller key wins, store the key subtracted from KEY_MASK so that the
+//greater HandRank is still the better hand, and set LOW_FLAG so the key can be recovered.
+//Aces playing low take the value 1.
+const KEY_MASK: u32 = (1 << 28) - 1;
+const LOW_FLAG: u32 = 1 << 31;
+
+/// The complete strength of a five card hand: its [`Ranking`] plus the ranks which break ties
+/// within that category.
+///
+/// Two `HandRank`s compare the way the hands they came from do, across categories as well as
+/// within one, so hands can be sorted, deduplicated or used as map keys by their rank.
+///
+/// Ranks produced under lowball [`RankingRules`] follow the same rule, the greater rank being the
+/// better hand under those rules. Only compare ranks produced under the same rules.
+///
+/// [`RankingRules`]: crate::RankingRules
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandRank(u32);
+
+impl HandRank {
+    fn new(strength: u8, ranking: Ranking, tie_break: &[u8]) -> Self {
+        Self(pack(strength, ranking.value(), tie_break))
+    }
+
+    //A rank packed by build.rs into the lookup tables, the inverse of as_u32.
+    pub(crate) fn from_u32(packed: u32) -> Self {
+        Self(packed)
+    }
+
+    //The best hand under lowball rules has the smallest key, so flip it over.
+    fn inverted(self) -> Self {
+        Self(LOW_FLAG | (KEY_MASK - self.0))
+    }
+
+    //The key as it was built, undoing inverted().
+    fn key(&self) -> u32 {
+        if self.0 & LOW_FLAG != 0 {
+            KEY_MASK - (self.0 & KEY_MASK)
+        } else {
+            self.0
+        }
+    }
+
+    /// The category of the hand.
+    pub fn ranking(&self) -> Ranking {
+        Ranking::from_value(((self.key() >> CATEGORY_SHIFT) & 0xF) as u8)
+            .expect("HandRank holds a valid category")
+    }
+
+    /// The ranks which decide between two hands of the same category, most important first.
+    ///
+    /// Grouped ranks appear once, ordered by the size of the group and then by rank, e.g. a full
+    /// house of kings over fives gives `[King, Five]`. A straight is represented by its highest
+    /// card alone. Where aces play low, as in ace-to-five lowball, they come after the twos.
+    pub fn tie_break(&self) -> impl Iterator<Item = Rank> {
+        let packed = self.key();
+        (0..TIE_BREAK_SLOTS)
+            .rev()
+            .map(move |slot| ((packed >> (slot * 4)) & 0xF) as u8)
+            .take_while(|&value| value != 0)
+            .filter_map(rank_of)
+    }
+
+    /// The packed representation. Larger values are stronger hands under the rules the rank was
+    /// produced by.
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+}
+
+//Rank::from_u8, with 1 standing for an ace played low.
+fn rank_of(value: u8) -> Option<Rank> {
+    match value {
+        1 => Some(Rank::Ace),
+        value => Rank::from_u8(value),
+    }
+}
+
+//Which parts of the evaluator apply. High poker uses all of them; the lowball rules switch some
+//off, see RankingRules.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Scoring {
+    //Aces count as 1 rather than 14.
+    pub(crate) ace_low: bool,
+    //Straights and flushes count at all.
+    pub(crate) straights_and_flushes: bool,
+    //The highest card of the straight an ace makes by playing low, e.g. 5 for A-2-3-4-5. None if
+    //an ace may not play low in a straight. Only meaningful with aces high.
+    pub(crate) wheel: Option<u8>,
+    //The categories from weakest to strongest, if they differ from Ranking's own order.
+    pub(crate) order: Option<&'static [Ranking; 10]>,
+    //The smallest key wins.
+    pub(crate) lowball: bool,
+}
+
+impl Scoring {
+    pub(crate) const HIGH: Scoring = Scoring {
+        ace_low: false,
+        straights_and_flushes: true,
+        wheel: Some(5),
+        order: None,
+        lowball: false,
+    };
+
+    fn strength(&self, ranking: Ranking) -> u8 {
+        match self.order {
+            Some(order) => order.iter().position(|&r| r == ranking).map_or(0, |i| i as u8 + 1),
+            None => ranking.value(),
+        }
+    }
+}
+
+/// Evaluates a five card hand.
+///
+/// The cards may be in any order. No allocation takes place, so this is suitable for tight loops.
+/// The cards are not validated here; go through [`Hand`] to reject impossible hands. To rank a
+/// hand under other rules, such as lowball, see [`RankingRules::evaluate`].
+///
+/// [`Hand`]: crate::Hand
+/// [`RankingRules::evaluate`]: crate::RankingRules::evaluate
+pub fn evaluate(cards: &[Card]) -> HandRank {
+    score(cards, Scoring::HIGH)
+}
+
+pub(crate) fn score(cards: &[Card], scoring: Scoring) -> HandRank {
+    let value_of = |card: &Card| match card.rank() {
+        Rank::Ace if scoring.ace_low => 1,
+        rank => rank.value(),
+    };
+
+    //Count the cards of each value; index 0 is never used, index 1 only by low aces.
+    let mut counts = [0u8; 15];
+    for card in cards {
+        counts[value_of(card) as usize] += 1;
+    }
+
+    //(count, value) for every value present, largest group first and then highest value first.
+    let mut groups = [(0u8, 0u8); TIE_BREAK_SLOTS];
+    let mut len = 0;
+    for value in (1..counts.len() as u8).rev() {
+        let count = counts[value as usize];
+        if count > 0 && len < groups.len() {
+            groups[len] = (count, value);
+            len += 1;
+        }
+    }
+    let groups = &mut groups[..len];
+    groups.sort_unstable_by(|a, b| b.cmp(a));
+
+    let flush = scoring.straights_and_flushes
+        && cards
+            .split_first()
+            .is_some_and(|(first, rest)| rest.iter().all(|card| card.suit() == first.suit()));
+
+    //Five distinct values in a run, an ace perhaps playing low. The cards themselves are left
+    //untouched.
+    let straight = if !scoring.straights_and_flushes || len != 5 {
+        None
+    } else {
+        let values = groups
+            .iter()
+            .fold(0, |values, &(_, value)| values | 1 << value);
+        straight_high(values, scoring.wheel)
+    };
+
+    let mut tie_break = [0u8; TIE_BREAK_SLOTS];
+    for (slot, &(_, value)) in tie_break.iter_mut().zip(groups.iter()) {
+        *slot = value;
+    }
+
+    let largest = |index: usize| groups.get(index).map_or(0, |group| group.0);
+    let ranking = match (largest(0), largest(1)) {
+        (5, _) => Ranking::FiveOfAKind,
+        _ if flush && straight.is_some() => Ranking::StraightFlush,
+        (4, _) => Ranking::FourOfAKind,
+        (3, 2) => Ranking::FullHouse,
+        _ if flush => Ranking::Flush,
+        _ if straight.is_some() => Ranking::Straight,
+        (3, _) => Ranking::ThreeOfAKind,
+        (2, 2) => Ranking::TwoPair,
+        (2, _) => Ranking::OnePair,
+        _ => Ranking::HighCard,
+    };
+
+    let rank = match (ranking, straight) {
+        (Ranking::StraightFlush, Some(high)) | (Ranking::Straight, Some(high)) => {
+            HandRank::new(scoring.strength(ranking), ranking, &[high])
+        }
+        _ => HandRank::new(scoring.strength(ranking), ranking, &tie_break[..len]),
+    };
+    if scoring.lowball {
+        rank.inverted()
+    } else {
+        rank
+    }
+}
+
+/// Finds the strongest five card hand which can be made from five to seven cards, e.g. two hole
+/// cards plus the board in Texas Hold'em.
+///
+/// Returns its rank together with the five cards used, from the highest rank to the lowest. When
+/// several combinations are equally strong, the first one found is returned. Like [`evaluate`],
+/// this does not allocate and does not validate the cards. Seven cards are ranked in one step by
+/// [`evaluate_seven`] rather than through their 21 five card subsets.
+///
+/// [`evaluate_seven`]: crate::evaluate_seven
+///
+/// # Panics
+///
+/// Panics if fewer than five or more than seven cards are given. Seven cards must also be
+/// different, as for [`evaluate_seven`].
+pub fn best_hand(cards: &[Card]) -> (HandRank, [Card; 5]) {
+    match <&[Card; 7]>::try_from(cards) {
+        Ok(seven) => best_seven(seven),
+        Err(_) => best_hand_by(cards, evaluate),
+    }
+}
+
+pub(crate) fn best_hand_by(
+    cards: &[Card],
+    evaluate: impl Fn(&[Card]) -> HandRank,
+) -> (HandRank, [Card; 5]) {
+    assert!(
+        (5..=7).contains(&cards.len()),
+        "best_hand takes 5 to 7 cards, but {} were given",
+        cards.len()
+    );
+
+    //Every subset of exactly five cards, as a bit mask over the input. With seven cards that is
+    //21 of the 128 masks, which is why seven cards under high rules go through best_seven instead.
+    let mut best: Option<(HandRank, [Card; 5])> = None;
+    for mask in 0u32..1 << cards.len() {
+        if mask.count_ones() != 5 {
+            continue;
+        }
+        let mut hand = [cards[0]; 5];
+        let chosen = cards
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| mask & (1 << index) != 0)
+            .map(|(_, card)| *card);
+        for (slot, card) in hand.iter_mut().zip(chosen) {
+            *slot = card;
+        }
+        let rank = evaluate(&hand);
+        if best.is_none_or(|(best_rank, _)| rank > best_rank) {
+            best = Some((rank, hand));
+        }
+    }
+
+    let (rank, mut hand) = best.expect("at least five cards give at least one hand");
+    sort_by_rank(&mut hand);
+    (rank, hand)
+}
+
+//Sorts from the highest rank to the lowest, keeping cards of the same rank in the order given.
+pub(crate) fn sort_by_rank(cards: &mut [Card]) {
+    sort_by_key(cards, |card| core::cmp::Reverse(card.rank()));
+}
+
+//A stable insertion sort, as slice::sort_by_key needs an allocator and a hand is only five cards.
+pub(crate) fn sort_by_key<K: Ord>(cards: &mut [Card], key: impl Fn(&Card) -> K) {
+    for i in 1..cards.len() {
+        let mut j = i;
+        while j > 0 && key(&cards[j - 1]) > key(&cards[j]) {
+            cards.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+/// The strength of a qualifying eight-or-better low hand, as played for the low half of the pot
+/// in hi-lo games such as Omaha Hi-Lo.
+///
+/// A low hand is five cards of different ranks, none higher than an eight, with aces counting
+/// low. Straights and flushes do not count against it. Hands are compared by their highest card,
+/// then the next highest and so on, the lower hand winning: 7-5-4-3-2 beats 8-4-3-2-A.
+///
+/// As with [`HandRank`], a greater `LowRank` is a stronger hand, i.e. the *lower* low.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LowRank(u32);
+
+impl LowRank {
+    /// The five ranks of the hand from the highest to the lowest, aces last.
+    pub fn ranks(&self) -> impl Iterator<Item = Rank> {
+        let packed = self.0;
+        (0..TIE_BREAK_SLOTS)
+            .rev()
+            .filter_map(move |slot| rank_of(((packed >> (slot * 4)) & 0xF) as u8))
+    }
+
+    /// The packed representation: the rank values from the highest card down, one nibble each,
+    /// with aces as 1. Unlike [`HandRank::as_u32`], *smaller* values are stronger hands.
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+}
+
+//Reversed, so that the lowest low compares as the greatest, the way HandRank's best hand does.
+impl PartialOrd for LowRank {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LowRank {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+/// Evaluates five cards as an eight-or-better low, returning `None` if they do not qualify.
+///
+/// Like [`evaluate`], this does not allocate and does not validate the cards.
+pub fn evaluate_low(cards: &[Card]) -> Option<LowRank> {
+    //A bit per value, with the ace moved down to 1. Any pair or card above an eight disqualifies.
+    let mut seen = 0u16;
+    for card in cards {
+        let value = match card.rank() {
+            Rank::Ace => 1,
+            rank => rank.value(),
+        };
+        if value > 8 || seen & (1 << value) != 0 {
+            return None;
+        }
+        seen |= 1 << value;
+    }
+    if cards.len() != TIE_BREAK_SLOTS {
+        return None;
+    }
+    let packed = (1..=8u32)
+        .rev()
+        .filter(|value| seen & (1 << value) != 0)
+        .fold(0, |acc, value| (acc << 4) | value);
+    Some(LowRank(packed))
+}
+
+//The indices of every entry sharing the greatest rank, i.e. the winners of a showdown. More than
+//one index means a split pot.
+#[cfg(feature = "std")]
+pub(crate) fn best_indices<T: Ord>(ranks: &[T]) -> Vec<usize> {
+    let best = ranks.iter().max();
+    ranks
+        .iter()
+        .enumerate()
+        .filter(|&(_, rank)| Some(rank) == best)
+        .map(|(index, _)| index)
+        .collect()
+}
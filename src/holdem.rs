@@ -0,0 +1,64 @@
+use crate::card::Card;
+use crate::error::PokerError;
+use crate::hand::{check_deal, check_size};
+use crate::hand_rank::{best_indices, HandRank};
+use crate::rules::RankingRules;
+
+/// The two cards dealt face down to a single Texas Hold'em player.
+pub type HoleCards = [Card; 2];
+
+/// The number of community cards on a complete Texas Hold'em board.
+pub const BOARD_SIZE: usize = 5;
+
+/// Given a complete board and the hole cards of every player still in the hand, returns the
+/// indices of the players who win.
+///
+/// Each player plays the best five cards out of their hole cards and the board, see
+/// [`best_hand`]. More than one index is returned when the pot is split.
+///
+/// The board must hold exactly five cards, and as everything is dealt from a single deck no card
+/// may appear twice. Errors about a player's hole cards carry that player's index; errors about
+/// the board carry `hole_cards.len()`, as if the board were dealt after the last player.
+///
+/// [`best_hand`]: crate::best_hand
+pub fn holdem_winners(board: &[Card], hole_cards: &[HoleCards]) -> Result<Vec<usize>, PokerError> {
+    holdem_winners_with(board, hole_cards, RankingRules::High)
+}
+
+/// Like [`holdem_winners`], but ranks the hands under the given rules, e.g.
+/// [`RankingRules::ShortDeck`].
+///
+/// As with [`winning_hands_with`], only hands which [qualify](RankingRules::qualifies) can win,
+/// so under [`RankingRules::EightOrBetter`] no index is returned when no player makes a low.
+///
+/// [`winning_hands_with`]: crate::winning_hands_with
+pub fn holdem_winners_with(
+    board: &[Card],
+    hole_cards: &[HoleCards],
+    rules: RankingRules,
+) -> Result<Vec<usize>, PokerError> {
+    check_size(board, BOARD_SIZE..=BOARD_SIZE).map_err(|error| error.in_hand(hole_cards.len()))?;
+    check_deal(
+        hole_cards
+            .iter()
+            .map(|hole| &hole[..])
+            .chain(std::iter::once(board)),
+    )?;
+
+    //None for a hand which does not qualify, so that it can only win if nobody qualifies, and
+    //then it is dropped below.
+    let ranks: Vec<Option<HandRank>> = hole_cards
+        .iter()
+        .map(|hole| {
+            let mut cards = [hole[0]; 7];
+            cards[..2].copy_from_slice(hole);
+            cards[2..].copy_from_slice(board);
+            Some(rules.best_rank(&cards)).filter(|&rank| rules.qualifies(rank))
+        })
+        .collect();
+
+    Ok(best_indices(&ranks)
+        .into_iter()
+        .filter(|&index| ranks[index].is_some())
+        .collect())
+}
@@ -1,43 +1,164 @@
+//! Pick the best hand(s) from a list of poker hands.
+//!
+//Most of what the crate offers needs std, so the overview of it is only there when std is on;
+//its links would not resolve otherwise.
+#![cfg_attr(
+    feature = "std",
+    doc = r#"
+[`winning_hands`] works directly on strings such as `"4S 5S 7H 8D JC"`. The types it is built
+on ([`Suit`], [`Rank`], [`Card`], [`Hand`], [`Ranking`] and [`HandRank`]) are exported as well, so
+the evaluator can be used without going through strings. [`Hand::describe`] puts a hand into
+words, and [`explain_winners`] says what settled a showdown. [`rank_hands`] places every hand,
+not only the winners. For simulations, [`evaluate_bits`] ranks cards packed by
+[`Card::to_bits`] through precomputed tables, and [`evaluate_seven`] ranks seven cards in one
+step.
+
+Many showdowns at once, such as recorded tables, go through [`winning_hands_batch`] and
+[`winning_parsed_hands_batch`]. With the `parallel` feature these, and the equity simulations
+below, spread their work across threads with rayon; the results are the same either way.
+
+For Texas Hold'em, [`best_hand`] picks the strongest five of up to seven cards and
+[`holdem_winners`] settles a showdown between several players sharing a board. Omaha, where a
+player must use exactly two hole cards, has [`omaha_winners`] and, for the hi-lo split,
+[`omaha_hi_lo_winners`].
+
+Lowball and short deck games rank hands differently; [`winning_hands_with`] and
+[`RankingRules::evaluate`] take the [`RankingRules`] to play by. Jokers and wild ranks are
+handled by [`evaluate_wild`] and [`winning_wild_hands`].
+
+A [`Deck`] shuffles from a seed and deals reproducibly. Before the board is complete,
+[`equity`] estimates each player's chances by sampling and [`exact_equity`] works them out by
+dealing every possible board. Starting hands can be grouped into a [`Range`] written in the
+usual notation, such as `"QQ+, AKs, A5s-A2s"`, and [`range_equity`] pits ranges against each
+other. On the flop or the turn, [`outs`] lists the cards which would put a hand ahead.
+
+Everything above needs the `std` feature, which is on by default.
+"#
+)]
+//!
+//! Without `std` the crate is `no_std` and allocates nothing: hands parse into fixed arrays, and
+//! [`Card`], [`CardSet`], [`Hand`], [`evaluate`], [`best_hand`], [`evaluate_bits`],
+//! [`evaluate_seven`] and [`RankingRules`] are all there is, small enough for embedded and WASM
+//! targets.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+mod batch;
+mod bits;
+mod card;
+mod card_set;
+#[cfg(feature = "std")]
+mod deck;
+#[cfg(feature = "std")]
+mod describe;
+#[cfg(feature = "std")]
+mod equity;
+mod error;
+mod hand;
+mod hand_rank;
+#[cfg(feature = "std")]
+mod holdem;
+mod lookup;
+#[cfg(feature = "std")]
+mod omaha;
+#[cfg(feature = "std")]
+mod outs;
+#[cfg(feature = "std")]
+mod range;
+#[cfg(feature = "std")]
+mod range_equity;
+mod ranking;
+#[cfg(feature = "std")]
+mod rng;
+mod rules;
+mod seven;
+#[cfg(feature = "std")]
+mod standings;
+mod tables;
+#[cfg(feature = "std")]
+mod wild;
+
+#[cfg(feature = "std")]
+use crate::batch::Scratch;
+
+#[cfg(feature = "std")]
+pub use crate::batch::{
+    try_winning_hands_batch, try_winning_hands_batch_with, winning_hands_batch,
+    winning_parsed_hands_batch,
+};
+pub use crate::card::{Card, CardFormat, Formatted, Rank, Suit};
+pub use crate::card_set::{CardSet, CardSetIter};
+#[cfg(feature = "std")]
+pub use crate::deck::Deck;
+#[cfg(feature = "std")]
+pub use crate::describe::{explain_winners, Decider, Explanation, HandDescription};
+#[cfg(feature = "std")]
+pub use crate::equity::{equity, equity_auto, exact_equity, remaining_boards, EquityResult};
+pub use crate::error::{CardError, ErrorText, PokerError};
+pub use crate::hand::Hand;
+pub use crate::hand_rank::{best_hand, evaluate, evaluate_low, HandRank, LowRank};
+#[cfg(feature = "std")]
+pub use crate::holdem::{holdem_winners, holdem_winners_with, HoleCards, BOARD_SIZE};
+pub use crate::lookup::evaluate_bits;
+#[cfg(feature = "std")]
+pub use crate::omaha::{
+    omaha_best_hand, omaha_best_low, omaha_hi_lo_winners, omaha_winners, HiLoWinners,
+    OMAHA_HOLE_CARDS,
+};
+#[cfg(feature = "std")]
+pub use crate::outs::{outs, Out, Outs};
+#[cfg(feature = "std")]
+pub use crate::range::Range;
+#[cfg(feature = "std")]
+pub use crate::range_equity::{range_equity, ComboEquity, RangeEquity};
+pub use crate::ranking::Ranking;
+pub use crate::rules::RankingRules;
+pub use crate::seven::evaluate_seven;
+#[cfg(feature = "std")]
+pub use crate::standings::{rank_hands, rank_parsed_hands, try_rank_hands, Standing};
+#[cfg(feature = "std")]
+pub use crate::wild::{
+    evaluate_wild, try_winning_wild_hands, winning_wild_hands, JokerRule, WildCard, WildRules,
+};
+
 /// Given a list of poker hands, return a list of those hands which win.
 ///
 /// Note the type signature: this function should return _the same_ reference to
 /// the winning hand(s) as were passed in, not reconstructed strings which happen to be equal.
-mod ranking;
-mod hand;
-use std::cmp::Ordering;
-use ranking::Ranking;
-use crate::hand::Hand;
-
+///
+/// Returns `None` if any hand fails to parse or the deal is impossible. Use [`try_winning_hands`]
+/// to find out why.
+#[cfg(feature = "std")]
 pub fn winning_hands<'a>(hands: &[&'a str]) -> Option<Vec<&'a str>> {
-    //Need to sort cards in a programattic manner. Maybe initialize Hand::new()
-    //&["4S 5S 7H 8D JC"] <-- All split by white space. split_whitespacee and arrange into
-    //Vec<Hand>
-    let mut final_hand: Vec<&str> = Vec::new();
-    let mut max_value = 0;
-
-    // Iter through each hand and construct Vec<Hand>. Determine the value of the ranking and
-    // compare. If eq, then a secondary check via Ranking::cmp needs to be determined. Else,
-    // return the greater hand.
-    for hand in hands.iter() {
-        let mut init_hand = Hand::new_sorted(hand);
-        let ranking = Ranking::determine_ranking(&mut init_hand);
-        let value = ranking.value();
-        if value.eq(&max_value) {
-            match Ranking::cmp(
-                ranking,
-                init_hand,
-                Hand::new_sorted(final_hand.first().unwrap()),
-            ) {
-                Ordering::Greater => final_hand = vec![hand],
-                Ordering::Less => (),
-                Ordering::Equal => final_hand.push(hand),
-            }
-        } else if value.gt(&max_value) {
-            max_value = value;
-            final_hand = vec![hand];
-        }
-    }
-
-    //Always return Some(_) as Error handling already implemented.
-    Some(final_hand)
+    try_winning_hands(hands).ok()
+}
+
+/// Like [`winning_hands`], but ranks the hands under the given rules, e.g. for lowball.
+///
+/// Hands which do not [qualify] under the rules never win, so the result may be empty.
+///
+/// [qualify]: RankingRules::qualifies
+#[cfg(feature = "std")]
+pub fn winning_hands_with<'a>(hands: &[&'a str], rules: RankingRules) -> Option<Vec<&'a str>> {
+    try_winning_hands_with(hands, rules).ok()
+}
+
+/// Fallible version of [`winning_hands`].
+///
+/// The first card which fails to parse is reported as a [`PokerError`], carrying the index of
+/// the offending hand within `hands` and the position of the card within that hand. Every hand
+/// must hold exactly five cards, and as the hands are dealt from a single deck no card may appear
+/// twice, whether within one hand or across two.
+#[cfg(feature = "std")]
+pub fn try_winning_hands<'a>(hands: &[&'a str]) -> Result<Vec<&'a str>, PokerError> {
+    try_winning_hands_with(hands, RankingRules::High)
+}
+
+/// Fallible version of [`winning_hands_with`]. Errors are reported as by [`try_winning_hands`].
+#[cfg(feature = "std")]
+pub fn try_winning_hands_with<'a>(
+    hands: &[&'a str],
+    rules: RankingRules,
+) -> Result<Vec<&'a str>, PokerError> {
+    Scratch::default().winners(hands, rules)
 }
@@ -0,0 +1,36 @@
+use crate::hand_rank::HandRank;
+use crate::tables::{DISTINCT, FLUSHES, PRODUCTS};
+
+//Cactus Kev's evaluator. A hand of five different ranks is looked up by its rank bits, in the
+//flush table if all five cards share a suit and in the other table if not. Any other hand has a
+//repeated rank, and is looked up by the product of its rank primes, which is the same for every
+//hand holding the same ranks and different for every other. The tables hold the very HandRank
+//evaluate would give, and are generated by build.rs.
+
+/// Evaluates a five card hand given as [`Card::to_bits`], using precomputed tables.
+///
+/// Gives the same [`HandRank`] as [`evaluate`] for every hand of five different cards, and for
+/// five of a kind, in a handful of table lookups and without allocating. It is meant for
+/// simulations which evaluate millions of hands; convert the cards once and keep the bits.
+///
+/// # Panics
+///
+/// May panic if any of the values did not come from [`Card::to_bits`].
+///
+/// [`Card::to_bits`]: crate::Card::to_bits
+/// [`evaluate`]: crate::evaluate
+pub fn evaluate_bits(cards: &[u32; 5]) -> HandRank {
+    let [a, b, c, d, e] = *cards;
+    let ranks = ((a | b | c | d | e) >> 16) as usize;
+    if a & b & c & d & e & 0xF000 != 0 && FLUSHES[ranks] != 0 {
+        return HandRank::from_u32(FLUSHES[ranks]);
+    }
+    if DISTINCT[ranks] != 0 {
+        return HandRank::from_u32(DISTINCT[ranks]);
+    }
+    let product = (a & 0xFF) * (b & 0xFF) * (c & 0xFF) * (d & 0xFF) * (e & 0xFF);
+    let index = PRODUCTS
+        .binary_search_by_key(&product, |&(product, _)| product)
+        .expect("every hand with a repeated rank is in the table");
+    HandRank::from_u32(PRODUCTS[index].1)
+}
@@ -0,0 +1,165 @@
+use std::ops::RangeInclusive;
+
+use crate::card::{Card, Rank};
+use crate::error::PokerError;
+use crate::hand::{check_deal, check_size};
+use crate::hand_rank::{best_indices, evaluate, evaluate_low, HandRank, LowRank};
+use crate::holdem::BOARD_SIZE;
+
+/// The number of hole cards each Omaha player may hold: four in classic Omaha (PLO), five or six
+/// in the bigger variants.
+pub const OMAHA_HOLE_CARDS: RangeInclusive<usize> = 4..=6;
+
+//The number of community cards needed before a hand can be made from exactly three of them.
+const MIN_BOARD: usize = 3;
+
+//Every five card hand an Omaha player may show: exactly two of their hole cards and exactly three
+//of the board. With six hole cards and a full board that is 15 * 10 = 150 hands.
+fn omaha_hands<'a>(hole: &'a [Card], board: &'a [Card]) -> impl Iterator<Item = [Card; 5]> + 'a {
+    let pairs =
+        (0..hole.len()).flat_map(move |a| (a + 1..hole.len()).map(move |b| [hole[a], hole[b]]));
+    pairs.flat_map(move |[a, b]| {
+        (0..board.len()).flat_map(move |c| {
+            (c + 1..board.len()).flat_map(move |d| {
+                (d + 1..board.len()).map(move |e| [a, b, board[c], board[d], board[e]])
+            })
+        })
+    })
+}
+
+fn assert_sizes(hole: &[Card], board: &[Card]) {
+    assert!(
+        OMAHA_HOLE_CARDS.contains(&hole.len()),
+        "Omaha takes 4 to 6 hole cards, but {} were given",
+        hole.len()
+    );
+    assert!(
+        (MIN_BOARD..=BOARD_SIZE).contains(&board.len()),
+        "Omaha takes 3 to 5 board cards, but {} were given",
+        board.len()
+    );
+}
+
+/// Finds the strongest high hand an Omaha player can make, using exactly two of their hole cards
+/// and exactly three cards of the board.
+///
+/// Returns its rank together with the five cards used, from the highest rank to the lowest. Hands
+/// are ranked exactly as by [`evaluate`]. The cards are not validated.
+///
+/// # Panics
+///
+/// Panics unless four to six hole cards and three to five board cards are given.
+pub fn omaha_best_hand(hole: &[Card], board: &[Card]) -> (HandRank, [Card; 5]) {
+    assert_sizes(hole, board);
+    let (rank, mut hand) = omaha_hands(hole, board)
+        .map(|hand| (evaluate(&hand), hand))
+        .max_by_key(|&(rank, _)| rank)
+        .expect("at least one hand can be made");
+    hand.sort_by_key(|card| std::cmp::Reverse(card.rank()));
+    (rank, hand)
+}
+
+/// Finds the best eight-or-better low an Omaha Hi-Lo player can make, again from exactly two hole
+/// cards and three board cards, or `None` if no such low exists.
+///
+/// The cards are returned from the highest to the lowest, aces last. See [`LowRank`].
+///
+/// # Panics
+///
+/// Panics unless four to six hole cards and three to five board cards are given.
+pub fn omaha_best_low(hole: &[Card], board: &[Card]) -> Option<(LowRank, [Card; 5])> {
+    assert_sizes(hole, board);
+    let (rank, mut hand) = omaha_hands(hole, board)
+        .filter_map(|hand| evaluate_low(&hand).map(|rank| (rank, hand)))
+        .max_by_key(|&(rank, _)| rank)?;
+    hand.sort_by_key(|card| match card.rank() {
+        Rank::Ace => std::cmp::Reverse(1),
+        rank => std::cmp::Reverse(rank.value()),
+    });
+    Some((rank, hand))
+}
+
+//The board must be complete, every player must hold a legal number of hole cards, and as
+//everything comes out of one deck no card may be dealt twice. The board is reported as the hand
+//after the last player.
+fn check_omaha_deal(board: &[Card], hands: &[&[Card]]) -> Result<(), PokerError> {
+    for (index, hole) in hands.iter().enumerate() {
+        check_size(hole, OMAHA_HOLE_CARDS).map_err(|error| error.in_hand(index))?;
+    }
+    check_size(board, BOARD_SIZE..=BOARD_SIZE).map_err(|error| error.in_hand(hands.len()))?;
+    check_deal(hands.iter().copied().chain(std::iter::once(board)))
+}
+
+/// Given a complete board and the hole cards of every player still in the hand, returns the
+/// indices of the players with the best Omaha high hand. More than one index means a split pot.
+///
+/// Every player must hold four to six hole cards; see [`holdem_winners`] for how errors are
+/// reported.
+///
+/// [`holdem_winners`]: crate::holdem_winners
+pub fn omaha_winners(board: &[Card], hands: &[&[Card]]) -> Result<Vec<usize>, PokerError> {
+    check_omaha_deal(board, hands)?;
+    Ok(high_winners(board, hands))
+}
+
+fn high_winners(board: &[Card], hands: &[&[Card]]) -> Vec<usize> {
+    let ranks: Vec<HandRank> = hands
+        .iter()
+        .map(|hole| omaha_best_hand(hole, board).0)
+        .collect();
+    best_indices(&ranks)
+}
+
+/// The players winning each half of an Omaha Hi-Lo pot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HiLoWinners {
+    /// The players sharing the high half, or the whole pot if nobody has a low.
+    pub high: Vec<usize>,
+    /// The players sharing the low half. Empty if no player made an eight-or-better low.
+    pub low: Vec<usize>,
+}
+
+impl HiLoWinners {
+    /// The fraction of the pot won by the given player.
+    ///
+    /// Each half is split evenly between its winners, so a player who wins the high outright and
+    /// ties the low with one other player takes three quarters, while the other is "quartered".
+    /// Without a qualifying low the high hand takes the whole pot.
+    pub fn share(&self, player: usize) -> f64 {
+        let half = |winners: &[usize], pot: f64| {
+            if winners.contains(&player) {
+                pot / winners.len() as f64
+            } else {
+                0.0
+            }
+        };
+        if self.low.is_empty() {
+            half(&self.high, 1.0)
+        } else {
+            half(&self.high, 0.5) + half(&self.low, 0.5)
+        }
+    }
+}
+
+/// Settles an Omaha Hi-Lo showdown: the pot is split between the best high hand and the best
+/// eight-or-better low, each made from exactly two hole cards and three board cards.
+///
+/// A player may use different hole cards for the high and the low. Errors are reported as by
+/// [`omaha_winners`].
+pub fn omaha_hi_lo_winners(board: &[Card], hands: &[&[Card]]) -> Result<HiLoWinners, PokerError> {
+    check_omaha_deal(board, hands)?;
+    let lows: Vec<Option<LowRank>> = hands
+        .iter()
+        .map(|hole| omaha_best_low(hole, board).map(|(rank, _)| rank))
+        .collect();
+    // None sorts below every low, so nobody wins the low unless someone qualified.
+    let low = if lows.iter().any(Option::is_some) {
+        best_indices(&lows)
+    } else {
+        Vec::new()
+    };
+    Ok(HiLoWinners {
+        high: high_winners(board, hands),
+        low,
+    })
+}
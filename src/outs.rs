@@ -0,0 +1,184 @@
+use std::cmp::Reverse;
+use std::fmt;
+
+use crate::card::Card;
+use crate::card_set::CardSet;
+use crate::error::{CardError, PokerError};
+use crate::hand::{check_deal, check_size};
+use crate::hand_rank::HandRank;
+use crate::holdem::{HoleCards, BOARD_SIZE};
+use crate::range::Range;
+use crate::ranking::Ranking;
+use crate::rules::RankingRules;
+
+/// A card which would improve a hand, as found by [`outs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Out {
+    pub card: Card,
+    /// The category the hand improves to.
+    pub ranking: Ranking,
+    /// Whether the card improves the opponent as well, so that it may not win after all.
+    pub tainted: bool,
+}
+
+/// The outs of a Texas Hold'em hand on the flop or the turn. See [`outs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outs {
+    outs: Vec<Out>,
+    //The cards which may still come, and how many of them will.
+    unseen: usize,
+    to_come: usize,
+}
+
+/// Finds the cards which would improve a hand on the flop or the turn to a category which beats
+/// the opponent.
+///
+/// A card is an out when it lifts the hand's [`Ranking`] above its current one, and the improved
+/// hand beats what the opponent holds now. It is tainted when it improves the opponent's category
+/// too. The opponent is a [`Range`], which may be a single combination such as `"AhKh"`; against
+/// a wider range each test counts the combinations which do not hold the card by their weight,
+/// and holds when it holds for more than half of them.
+///
+/// The board must hold three or four cards. Errors about the hole cards carry 0 and errors about
+/// the board 1; [`CardError::NoCombination`] with 2 means no combination of the range can be held
+/// alongside them.
+pub fn outs(hole: HoleCards, board: &[Card], opponent: &Range) -> Result<Outs, PokerError> {
+    check_size(board, 3..=BOARD_SIZE - 1).map_err(|error| error.in_hand(1))?;
+    check_deal([&hole[..], board])?;
+
+    let mut known = hole.to_vec();
+    known.extend_from_slice(board);
+    let opponent = opponent.without(&known);
+    let combos: Vec<(HoleCards, f64)> = opponent.combos().collect();
+    if combos.is_empty() {
+        return Err(PokerError {
+            hand: 2,
+            position: 0,
+            source: CardError::NoCombination,
+        });
+    }
+    //Cards held by every combination of the range are as good as seen.
+    let held = combos.iter().fold(CardSet::FULL, |held, (combo, _)| {
+        held & combo.iter().collect()
+    });
+    let unseen: Vec<Card> = (!known.iter().collect::<CardSet>() - held).iter().collect();
+
+    let rank = |hole: &HoleCards, extra: Option<Card>| {
+        let mut cards = hole.to_vec();
+        cards.extend_from_slice(board);
+        cards.extend(extra);
+        RankingRules::High.best_rank(&cards)
+    };
+    let current = rank(&hole, None).ranking();
+    let opponent_now: Vec<HandRank> = combos.iter().map(|(combo, _)| rank(combo, None)).collect();
+    let outs = unseen
+        .iter()
+        .filter_map(|&card| {
+            let improved = rank(&hole, Some(card));
+            if improved.ranking() <= current {
+                return None;
+            }
+            //Only the combinations which do not hold the card can still be out there.
+            let live = || {
+                combos
+                    .iter()
+                    .zip(&opponent_now)
+                    .filter(move |((combo, _), _)| !combo.contains(&card))
+            };
+            let total: f64 = live().map(|((_, weight), _)| weight).sum();
+            let most = |test: &dyn Fn(&HoleCards, HandRank) -> bool| {
+                let held: f64 = live()
+                    .filter(|((combo, _), &now)| test(combo, now))
+                    .map(|((_, weight), _)| weight)
+                    .sum();
+                held > total / 2.0
+            };
+            if !most(&|_, now| improved > now) {
+                return None;
+            }
+            Some(Out {
+                card,
+                ranking: improved.ranking(),
+                tainted: most(&|combo, now| rank(combo, Some(card)).ranking() > now.ranking()),
+            })
+        })
+        .collect();
+    Ok(Outs {
+        outs,
+        unseen: unseen.len(),
+        to_come: BOARD_SIZE - board.len(),
+    })
+}
+
+impl Outs {
+    /// Every out, in [`Card::deck`] order.
+    pub fn cards(&self) -> &[Out] {
+        &self.outs
+    }
+
+    /// The outs grouped by the category they improve to, the strongest first.
+    pub fn by_ranking(&self) -> Vec<(Ranking, Vec<Out>)> {
+        let mut groups: Vec<(Ranking, Vec<Out>)> = Vec::new();
+        for &out in &self.outs {
+            match groups
+                .iter_mut()
+                .find(|(ranking, _)| *ranking == out.ranking)
+            {
+                Some((_, group)) => group.push(out),
+                None => groups.push((out.ranking, vec![out])),
+            }
+        }
+        groups.sort_by_key(|&(ranking, _)| Reverse(ranking));
+        groups
+    }
+
+    /// The number of outs.
+    pub fn len(&self) -> usize {
+        self.outs.len()
+    }
+
+    /// Whether nothing improves the hand enough.
+    pub fn is_empty(&self) -> bool {
+        self.outs.is_empty()
+    }
+
+    /// The chance that at least one out comes by the river: one card to come on the turn, two on
+    /// the flop.
+    pub fn probability(&self) -> f64 {
+        self.hit(self.outs.len())
+    }
+
+    /// Like [`Outs::probability`], counting only the outs which are not tainted.
+    pub fn clean_probability(&self) -> f64 {
+        self.hit(self.outs.iter().filter(|out| !out.tainted).count())
+    }
+
+    fn hit(&self, outs: usize) -> f64 {
+        //One minus the chance that every card to come misses.
+        let misses = (0..self.to_come).fold(1.0, |misses, i| {
+            misses * self.unseen.saturating_sub(outs + i) as f64 / (self.unseen - i) as f64
+        });
+        1.0 - misses
+    }
+}
+
+//Reads as "9 outs to Flush (2 tainted), 2 to Three of a Kind".
+impl fmt::Display for Outs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.outs.is_empty() {
+            return f.write_str("no outs");
+        }
+        for (i, (ranking, group)) in self.by_ranking().iter().enumerate() {
+            match (i, group.len()) {
+                (0, 1) => write!(f, "1 out to {}", ranking)?,
+                (0, n) => write!(f, "{} outs to {}", n, ranking)?,
+                (_, n) => write!(f, ", {} to {}", n, ranking)?,
+            }
+            let tainted = group.iter().filter(|out| out.tainted).count();
+            if tainted > 0 {
+                write!(f, " ({} tainted)", tainted)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,444 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::card::{Card, Rank, Suit};
+use crate::card_set::CardSet;
+use crate::error::{text, CardError};
+use crate::holdem::HoleCards;
+
+//Every combo has a slot of its own, found from the Card::index of its two cards.
+const COMBOS: usize = 52 * 51 / 2;
+
+fn slot(a: Card, b: Card) -> usize {
+    let (a, b) = (a.index() as usize, b.index() as usize);
+    let (low, high) = (a.min(b), a.max(b));
+    high * (high - 1) / 2 + low
+}
+
+//Every card from the aces down, each rank in Suit::ALL order, which is the order combos are
+//listed in.
+fn by_rank() -> impl Iterator<Item = Card> + Clone {
+    Rank::ALL
+        .iter()
+        .rev()
+        .flat_map(|&rank| Suit::ALL.iter().map(move |&suit| Card::new(rank, suit)))
+}
+
+/// A weighted set of Texas Hold'em starting hands, written in the usual range notation.
+///
+/// A range is parsed from a comma separated list such as `"QQ+, AKs, A5s-A2s, 76s+, AhKh"`:
+///
+/// - `QQ` is a pair, `AKs` and `AKo` the suited and offsuit hands, and `AK` both.
+/// - `QQ+` adds every higher pair. `A9s+` raises the kicker up to `AKs`, but a connector such as
+///   `76s+` moves both cards up together: `76s, 87s, 98s` and so on up to `AKs`.
+/// - `TT-77`, `A5s-A2s` and `KQo-T9o` span from one hand to the other.
+/// - `AhKh` is a single combination.
+/// - Any item may carry a weight from 0 to 1, as in `AKs:0.5`, the fraction of the time the hand
+///   is played. Later items override earlier ones.
+///
+/// Every range prints back out in a canonical, compact form of the same notation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Range {
+    //The weight of every combo, by its slot.
+    weights: Vec<f64>,
+}
+
+impl Default for Range {
+    fn default() -> Self {
+        Self {
+            weights: vec![0.0; COMBOS],
+        }
+    }
+}
+
+impl Range {
+    /// Parses a range such as `"QQ+, AKs:0.5, A5s-A2s"`. See [`Range`] for the notation.
+    pub fn parse(range: &str) -> Result<Self, CardError> {
+        let mut parsed = Self::default();
+        for item in range
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+        {
+            parsed.add(item)?;
+        }
+        Ok(parsed)
+    }
+
+    /// The weight given to a combination, from 0 (never played) to 1.
+    pub fn weight(&self, hole: HoleCards) -> f64 {
+        if hole[0] == hole[1] {
+            return 0.0;
+        }
+        self.weights[slot(hole[0], hole[1])]
+    }
+
+    /// Every combination in the range with its weight, the higher card first. Combinations are
+    /// ordered by their cards, aces first, and those with no weight are left out.
+    pub fn combos(&self) -> impl Iterator<Item = (HoleCards, f64)> + '_ {
+        by_rank().enumerate().flat_map(move |(i, high)| {
+            by_rank().skip(i + 1).filter_map(move |low| {
+                let weight = self.weights[slot(high, low)];
+                Some(([high, low], weight)).filter(|_| weight > 0.0)
+            })
+        })
+    }
+
+    /// The number of combinations in the range, however small their weight.
+    pub fn len(&self) -> usize {
+        self.combos().count()
+    }
+
+    /// Whether the range holds no combination at all.
+    pub fn is_empty(&self) -> bool {
+        self.combos().next().is_none()
+    }
+
+    /// The same range without the combinations which hold any of the given cards, e.g. the
+    /// board or another player's known hole cards.
+    pub fn without(&self, dead: &[Card]) -> Self {
+        let dead: CardSet = dead.iter().collect();
+        let mut range = self.clone();
+        for card in dead {
+            for other in !CardSet::from(card) {
+                range.weights[slot(card, other)] = 0.0;
+            }
+        }
+        range
+    }
+
+    fn set(&mut self, a: Card, b: Card, weight: f64) {
+        if a != b {
+            self.weights[slot(a, b)] = weight;
+        }
+    }
+
+    //Sets every combo of a pair, or of the hand of two different ranks.
+    fn set_class(&mut self, class: Class, weight: f64) {
+        for (i, &first) in Suit::ALL.iter().enumerate() {
+            for &second in &Suit::ALL[if class.high == class.low { i + 1 } else { 0 }..] {
+                let suited = first == second;
+                let wanted = match class.suited {
+                    Some(want) => want == suited,
+                    None => true,
+                };
+                if wanted {
+                    self.set(
+                        Card::new(class.high, first),
+                        Card::new(class.low, second),
+                        weight,
+                    );
+                }
+            }
+        }
+    }
+
+    fn add(&mut self, item: &str) -> Result<(), CardError> {
+        let error = || CardError::IncorrectRange { item: text(item) };
+        let (hands, weight) = match item.split_once(':') {
+            Some((hands, weight)) => {
+                let weight: f64 = weight.trim().parse().map_err(|_| error())?;
+                if !(0.0..=1.0).contains(&weight) {
+                    return Err(error());
+                }
+                (hands.trim(), weight)
+            }
+            None => (item, 1.0),
+        };
+
+        //A single combo, two cards back to back.
+        let split = Card::first_len(hands);
+        if let (Ok(a), Ok(b)) = (
+            hands[..split].parse::<Card>(),
+            hands[split..].parse::<Card>(),
+        ) {
+            if a == b {
+                return Err(error());
+            }
+            self.set(a, b, weight);
+            return Ok(());
+        }
+
+        for class in expand(hands).ok_or_else(error)? {
+            self.set_class(class, weight);
+        }
+        Ok(())
+    }
+}
+
+//A group of combos sharing their ranks: a pair, or two ranks suited, offsuit or either.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Class {
+    high: Rank,
+    low: Rank,
+    suited: Option<bool>,
+}
+
+impl Class {
+    fn parse(class: &str) -> Option<Self> {
+        let mut chars = class.chars();
+        let rank = |c: Option<char>| c?.to_string().parse::<Rank>().ok();
+        let (first, second) = (rank(chars.next())?, rank(chars.next())?);
+        let suited = match (chars.next().map(|c| c.to_ascii_lowercase()), chars.next()) {
+            (None, _) => None,
+            (Some('s'), None) if first != second => Some(true),
+            (Some('o'), None) if first != second => Some(false),
+            _ => return None,
+        };
+        Some(Self {
+            high: first.max(second),
+            low: first.min(second),
+            suited,
+        })
+    }
+
+    fn shifted(self, by: i8) -> Option<Self> {
+        let shift = |rank: Rank| Rank::from_u8((rank.value() as i8 + by) as u8);
+        Some(Self {
+            high: shift(self.high)?,
+            low: shift(self.low)?,
+            ..self
+        })
+    }
+
+    fn with_low(self, low: u8) -> Option<Self> {
+        Some(Self {
+            low: Rank::from_u8(low)?,
+            ..self
+        })
+    }
+
+    fn gap(&self) -> u8 {
+        self.high.value() - self.low.value()
+    }
+}
+
+//The classes an item such as "QQ+", "A5s-A2s" or "AKo" stands for.
+fn expand(hands: &str) -> Option<Vec<Class>> {
+    if let Some(class) = hands.strip_suffix('+') {
+        let class = Class::parse(class)?;
+        return Some(if class.high == class.low || class.gap() == 1 {
+            //Pairs and connectors move up together, as far as aces.
+            (0..).map_while(|by| class.shifted(by)).collect()
+        } else {
+            //Anything else raises its kicker up to one below the high card.
+            (class.low.value()..class.high.value())
+                .filter_map(|low| class.with_low(low))
+                .collect()
+        });
+    }
+
+    if let Some((from, to)) = hands.split_once('-') {
+        let (from, to) = (Class::parse(from.trim())?, Class::parse(to.trim())?);
+        let (top, bottom) = if from.high >= to.high && from.low >= to.low {
+            (from, to)
+        } else {
+            (to, from)
+        };
+        if top.suited != bottom.suited || top.low < bottom.low {
+            return None;
+        }
+        return if top.high == bottom.high && top.high != top.low {
+            //Same high card, the kicker spans: A5s-A2s.
+            Some(
+                (bottom.low.value()..=top.low.value())
+                    .rev()
+                    .filter_map(|low| top.with_low(low))
+                    .collect(),
+            )
+        } else if top.gap() == bottom.gap() {
+            //Both cards move together: TT-77, KQs-76s.
+            let steps = (top.high.value() - bottom.high.value()) as i8;
+            Some((0..=steps).filter_map(|by| top.shifted(-by)).collect())
+        } else {
+            None
+        };
+    }
+
+    Class::parse(hands).map(|class| vec![class])
+}
+
+/// Accepts the notation described on [`Range`].
+impl FromStr for Range {
+    type Err = CardError;
+
+    fn from_str(range: &str) -> Result<Self, Self::Err> {
+        Self::parse(range)
+    }
+}
+
+//The class of each kind written out by Display: both suited and offsuit, suited, or offsuit.
+fn kind_suited(kind: usize) -> Option<bool> {
+    [None, Some(true), Some(false)][kind]
+}
+
+//The weight every combo of a class shares, or None if they differ or none is in the range.
+fn class_weight(range: &Range, class: Class) -> Option<f64> {
+    let mut probe = Range::default();
+    probe.set_class(class, 1.0);
+    let mut weights = probe.combos().map(|(hole, _)| range.weight(hole));
+    let first = weights.next()?;
+    Some(first).filter(|&weight| weight > 0.0 && weights.all(|other| other == first))
+}
+
+//The runs of classes which share a weight, as (start, end, weight) with classes[start] the
+//strongest. Classes without a weight are left out.
+fn runs(classes: &[(Class, Option<f64>)]) -> Vec<(usize, usize, f64)> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    while start < classes.len() {
+        let weight = classes[start].1;
+        let end = start
+            + classes[start..]
+                .iter()
+                .take_while(|(_, other)| *other == weight)
+                .count();
+        if let Some(weight) = weight {
+            runs.push((start, end, weight));
+        }
+        start = end;
+    }
+    runs
+}
+
+//Writes out a run found by `runs`, e.g. "QQ+", "TT-77" or "A5s-A2s". `top` says whether the first
+//of the classes is the strongest class its "+" could reach.
+fn run_name(
+    classes: &[(Class, Option<f64>)],
+    (start, end, weight): (usize, usize, f64),
+    top: bool,
+    name: impl Fn(Class) -> String,
+) -> String {
+    let (high, low) = (classes[start].0, classes[end - 1].0);
+    let mut item = if start == 0 && top && end - start > 1 {
+        format!("{}+", name(low))
+    } else if end - start == 1 {
+        name(high)
+    } else {
+        format!("{}-{}", name(high), name(low))
+    };
+    if weight != 1.0 {
+        item.push_str(&format!(":{}", weight));
+    }
+    item
+}
+
+//Canonical form: pairs from aces down, then for each high card the hands played both suited and
+//offsuit, then suited only, then offsuit only, each as runs of kickers or as runs of the same gap
+//across high cards. Whatever is left, combos whose class is only partly in the range or has mixed
+//weights, follows one combo at a time.
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranks: Vec<Rank> = Rank::ALL.iter().rev().copied().collect();
+        let mut items = Vec::new();
+        let mut covered = Range::default();
+
+        let pairs: Vec<(Class, Option<f64>)> = ranks
+            .iter()
+            .map(|&rank| {
+                let class = Class {
+                    high: rank,
+                    low: rank,
+                    suited: None,
+                };
+                (class, class_weight(self, class))
+            })
+            .collect();
+        for run in runs(&pairs) {
+            items.push(run_name(&pairs, run, true, |class| {
+                format!("{}{}", class.high, class.low)
+            }));
+        }
+
+        //Kickers under the same high card are run together first, e.g. "A5s-A2s". A class which that
+        //leaves on its own is run together with the same gap under other high cards instead, e.g.
+        //"KQo-T9o". Items are listed by their strongest class.
+        let suffixes = ["", "s", "o"];
+        let mut weights = vec![vec![[None; 3]; ranks.len()]; ranks.len()];
+        let mut listed = Vec::new();
+        for (i, &high) in ranks.iter().enumerate() {
+            let class = |low, suited| Class { high, low, suited };
+            for (j, &low) in ranks.iter().enumerate().skip(i + 1) {
+                let s = class_weight(self, class(low, Some(true)));
+                let o = class_weight(self, class(low, Some(false)));
+                weights[i][j] = if s.is_some() && s == o {
+                    [s, None, None]
+                } else {
+                    [None, s, o]
+                };
+            }
+            for (kind, suffix) in suffixes.iter().enumerate() {
+                let classes: Vec<(Class, Option<f64>)> = ranks[i + 1..]
+                    .iter()
+                    .zip(&weights[i][i + 1..])
+                    .map(|(&low, weights)| (class(low, kind_suited(kind)), weights[kind]))
+                    .collect();
+                for run in runs(&classes) {
+                    let (start, end, _) = run;
+                    if end - start == 1 {
+                        continue;
+                    }
+                    listed.push((
+                        (i, kind, i + 1 + start),
+                        run_name(&classes, run, true, |class| {
+                            format!("{}{}{}", class.high, class.low, suffix)
+                        }),
+                    ));
+                    for weights in &mut weights[i][i + 1 + start..i + 1 + end] {
+                        weights[kind] = None;
+                    }
+                }
+            }
+        }
+        for gap in 1..ranks.len() {
+            for (kind, suffix) in suffixes.iter().enumerate() {
+                let classes: Vec<(Class, Option<f64>)> = (0..ranks.len() - gap)
+                    .map(|i| {
+                        let class = Class {
+                            high: ranks[i],
+                            low: ranks[i + gap],
+                            suited: kind_suited(kind),
+                        };
+                        (class, weights[i][i + gap][kind])
+                    })
+                    .collect();
+                //Connectors from the top move up to aces with a "+"; other gaps raise the kicker.
+                for run in runs(&classes) {
+                    listed.push((
+                        (run.0, kind, run.0 + gap),
+                        run_name(&classes, run, gap == 1, |class| {
+                            format!("{}{}{}", class.high, class.low, suffix)
+                        }),
+                    ));
+                }
+            }
+        }
+        listed.sort_by_key(|&(key, _)| key);
+        items.extend(listed.into_iter().map(|(_, item)| item));
+
+        //Mark what the classes above covered, then list the rest combo by combo.
+        for (i, &high) in ranks.iter().enumerate() {
+            for &low in &ranks[i..] {
+                for &suited in &[None, Some(true), Some(false)] {
+                    if high == low && suited.is_some() {
+                        continue;
+                    }
+                    let class = Class { high, low, suited };
+                    if let Some(weight) = class_weight(self, class) {
+                        covered.set_class(class, weight);
+                    }
+                }
+            }
+        }
+        for (hole, weight) in self.combos() {
+            if covered.weight(hole) != weight {
+                let mut item = format!("{}{}", hole[0], hole[1]);
+                if weight != 1.0 {
+                    item.push_str(&format!(":{}", weight));
+                }
+                items.push(item);
+            }
+        }
+        f.write_str(&items.join(", "))
+    }
+}
@@ -0,0 +1,258 @@
+use crate::batch::simulate;
+use crate::card::Card;
+use crate::card_set::CardSet;
+use crate::equity::{exact_equity, remaining_boards, Showdown};
+use crate::error::{CardError, PokerError};
+use crate::hand::{check_deal, check_size};
+use crate::holdem::{HoleCards, BOARD_SIZE};
+use crate::range::Range;
+
+/// How one range fared in [`range_equity`], overall and combination by combination.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeEquity {
+    /// The share of the pot this range can expect, over every combination it may hold.
+    pub equity: f64,
+    /// Every combination of the range which can be dealt alongside the board, in
+    /// [`Range::combos`] order.
+    pub combos: Vec<ComboEquity>,
+    /// Whether every deal was played out exactly once, so the figures are exact rather than
+    /// estimates.
+    pub exhaustive: bool,
+}
+
+/// How one combination of a range fared in [`range_equity`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComboEquity {
+    pub hole: HoleCards,
+    /// How often the range holds this combination, once the cards held by the board and the
+    /// other ranges are taken into account. The frequencies of a range add up to 1.
+    pub frequency: f64,
+    /// The share of the pot this combination can expect when it is held, or 0 if it never was.
+    pub equity: f64,
+}
+
+/// Works out each range's chances in a Texas Hold'em showdown, where every player holds some
+/// combination of their [`Range`] rather than known hole cards.
+///
+/// Combinations are dealt in proportion to their weights, and never two holding the same card,
+/// so a range is less likely to hold aces when another range is full of them. When there are no
+/// more than `iterations` deals to play out, counting every non-conflicting assignment of
+/// combinations and every rest of the board, they are all played out, as by [`exact_equity`].
+/// Otherwise `iterations` deals are sampled, and the same seed always samples the same deals,
+/// with or without the `parallel` feature spreading them across threads.
+///
+/// Fails if the board holds more than five cards or a card twice, with the error carrying
+/// `ranges.len()`, with [`CardError::NotEnoughCards`], also carrying `ranges.len()`, if there are
+/// too many ranges to leave enough cards for the board, and with [`CardError::NoCombination`],
+/// carrying the range's index, if a range cannot be dealt alongside the board and the others.
+pub fn range_equity(
+    ranges: &[Range],
+    board: &[Card],
+    iterations: u64,
+    seed: u64,
+) -> Result<Vec<RangeEquity>, PokerError> {
+    check_size(board, 0..=BOARD_SIZE).map_err(|error| error.in_hand(ranges.len()))?;
+    check_deal(std::iter::once(board)).map_err(|error| error.in_hand(ranges.len()))?;
+    //Whatever the ranges hold, they leave this many cards to finish the board with.
+    let unknown = CardSet::FULL
+        .len()
+        .saturating_sub(2 * ranges.len() + board.len());
+    if unknown < BOARD_SIZE - board.len() {
+        return Err(PokerError {
+            hand: ranges.len(),
+            position: 0,
+            source: CardError::NotEnoughCards {
+                requested: BOARD_SIZE - board.len(),
+                remaining: unknown,
+            },
+        });
+    }
+
+    let combos: Vec<Vec<(HoleCards, f64)>> = ranges
+        .iter()
+        .map(|range| range.without(board).combos().collect())
+        .collect();
+    let mut deepest = 0;
+    if !dealable(&combos, CardSet::EMPTY, &mut deepest) {
+        return Err(PokerError {
+            hand: deepest,
+            position: 0,
+            source: CardError::NoCombination,
+        });
+    }
+
+    let assignments = combos.iter().fold(1u64, |product, combos| {
+        product.saturating_mul(combos.len() as u64)
+    });
+    let boards = remaining_boards(ranges.len(), board.len(), 0);
+    let mut tally = Tally::new(&combos);
+    let exhaustive = assignments.saturating_mul(boards) <= iterations;
+    if exhaustive {
+        //Every assignment of combinations, as an index into each range's combinations.
+        let mut chosen = vec![0; combos.len()];
+        let mut players = Vec::with_capacity(combos.len());
+        loop {
+            players.clear();
+            players.extend(chosen.iter().zip(&combos).map(|(&i, combos)| combos[i].0));
+            if let Ok(results) = exact_equity(&players, board, &[]) {
+                let weight = chosen
+                    .iter()
+                    .zip(&combos)
+                    .map(|(&i, combos)| combos[i].1)
+                    .product();
+                let shares = results.iter().map(|result| result.equity());
+                tally.record(&chosen, weight, shares);
+            }
+            match (0..chosen.len())
+                .rev()
+                .find(|&i| chosen[i] + 1 < combos[i].len())
+            {
+                Some(i) => {
+                    chosen[i] += 1;
+                    for later in &mut chosen[i + 1..] {
+                        *later = 0;
+                    }
+                }
+                None => break,
+            }
+        }
+    } else {
+        //Running totals of the weights, to pick combinations in proportion to them.
+        let totals: Vec<Vec<f64>> = combos
+            .iter()
+            .map(|combos| {
+                combos
+                    .iter()
+                    .scan(0.0, |total, &(_, weight)| {
+                        *total += weight;
+                        Some(*total)
+                    })
+                    .collect()
+            })
+            .collect();
+        let chunks = simulate(iterations, seed, |rng, deals| {
+            let mut tally = Tally::new(&combos);
+            let mut chosen = vec![0; combos.len()];
+            let mut players = Vec::with_capacity(combos.len());
+            for _ in 0..deals {
+                //Deal every range a combination, starting over whenever two share a card.
+                loop {
+                    players.clear();
+                    for ((choice, combos), totals) in chosen.iter_mut().zip(&combos).zip(&totals)
+                    {
+                        let target = rng.unit() * totals[totals.len() - 1];
+                        *choice = totals
+                            .partition_point(|&total| total <= target)
+                            .min(combos.len() - 1);
+                        players.push(combos[*choice].0);
+                    }
+                    let dealt: CardSet = players.iter().flatten().collect();
+                    if dealt.len() == 2 * players.len() {
+                        break;
+                    }
+                }
+                let mut showdown = Showdown::new(&players, board, &[])?;
+                let (missing, deck_len) = (showdown.missing(), showdown.deck.len());
+                for i in 0..missing {
+                    showdown.deck.swap(i, i + rng.below(deck_len - i));
+                }
+                let indices = [0, 1, 2, 3, 4];
+                let winners = showdown.winners(&indices[..missing]);
+                let shares = (0..players.len()).map(|player| {
+                    if winners.contains(&player) {
+                        1.0 / winners.len() as f64
+                    } else {
+                        0.0
+                    }
+                });
+                tally.record(&chosen, 1.0, shares);
+            }
+            Ok(tally)
+        });
+        for chunk in chunks {
+            tally.absorb(chunk?);
+        }
+    }
+    Ok(tally.finish(&combos, exhaustive))
+}
+
+//Whether every range from the given one on can be dealt a combination holding none of the used
+//cards, noting the furthest range reached.
+fn dealable(combos: &[Vec<(HoleCards, f64)>], used: CardSet, deepest: &mut usize) -> bool {
+    let range = used.len() / 2;
+    *deepest = (*deepest).max(range);
+    if range == combos.len() {
+        return true;
+    }
+    combos[range].iter().any(|(hole, _)| {
+        let hole: CardSet = hole.iter().collect();
+        hole.is_disjoint(used) && dealable(combos, used | hole, deepest)
+    })
+}
+
+//The weight with which each combination was dealt, and the weighted pot shares it won.
+struct Tally {
+    weights: Vec<Vec<f64>>,
+    shares: Vec<Vec<f64>>,
+    total: f64,
+}
+
+impl Tally {
+    fn new(combos: &[Vec<(HoleCards, f64)>]) -> Self {
+        let zeros: Vec<Vec<f64>> = combos
+            .iter()
+            .map(|combos| vec![0.0; combos.len()])
+            .collect();
+        Self {
+            weights: zeros.clone(),
+            shares: zeros,
+            total: 0.0,
+        }
+    }
+
+    fn record(&mut self, chosen: &[usize], weight: f64, shares: impl Iterator<Item = f64>) {
+        self.total += weight;
+        for ((range, &i), share) in chosen.iter().enumerate().zip(shares) {
+            self.weights[range][i] += weight;
+            self.shares[range][i] += weight * share;
+        }
+    }
+
+    //Adds in the deals another tally of the same ranges counted.
+    fn absorb(&mut self, other: Tally) {
+        self.total += other.total;
+        for (mine, theirs) in self
+            .weights
+            .iter_mut()
+            .chain(&mut self.shares)
+            .zip(other.weights.iter().chain(&other.shares))
+        {
+            for (mine, theirs) in mine.iter_mut().zip(theirs) {
+                *mine += theirs;
+            }
+        }
+    }
+
+    fn finish(self, combos: &[Vec<(HoleCards, f64)>], exhaustive: bool) -> Vec<RangeEquity> {
+        //Nothing recorded, e.g. with no iterations, leaves every figure at 0 rather than NaN.
+        let total = self.total;
+        let rate = |count: f64| if total > 0.0 { count / total } else { 0.0 };
+        combos
+            .iter()
+            .zip(self.weights.iter().zip(&self.shares))
+            .map(|(combos, (weights, shares))| RangeEquity {
+                equity: rate(shares.iter().sum()),
+                combos: combos
+                    .iter()
+                    .zip(weights.iter().zip(shares))
+                    .map(|(&(hole, _), (&weight, &share))| ComboEquity {
+                        hole,
+                        frequency: rate(weight),
+                        equity: if weight > 0.0 { share / weight } else { 0.0 },
+                    })
+                    .collect(),
+                exhaustive,
+            })
+            .collect()
+    }
+}
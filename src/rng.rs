@@ -0,0 +1,76 @@
+//A small, fast and fully deterministic generator, so that a seed gives the same shuffle on every
+//platform and every release: xoshiro256** (Blackman and Vigna), seeded through SplitMix64 as its
+//authors recommend. Changing anything here changes every seeded shuffle, which the tests pin.
+#[derive(Debug, Clone)]
+pub(crate) struct Rng {
+    state: [u64; 4],
+}
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Self {
+        let mut seed = seed;
+        let mut split_mix = || {
+            seed = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = seed;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        };
+        Self {
+            state: [split_mix(), split_mix(), split_mix(), split_mix()],
+        }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        let [a, b, c, d] = &mut self.state;
+        let result = b.wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = *b << 17;
+        *c ^= *a;
+        *d ^= *b;
+        *b ^= *c;
+        *a ^= *d;
+        *c ^= t;
+        *d = d.rotate_left(45);
+        result
+    }
+
+    //Moves on as far as 2^128 calls to next_u64 would, so that generators jumped from the same
+    //seed give streams which never overlap.
+    pub(crate) fn jump(&mut self) {
+        const JUMP: [u64; 4] = [
+            0x180E_C6D3_3CFD_0ABA,
+            0xD5A6_1266_F0C9_392C,
+            0xA958_2618_E03F_C9AA,
+            0x39AB_DC45_29B1_661C,
+        ];
+        let mut state = [0; 4];
+        for &word in &JUMP {
+            for bit in 0..64 {
+                if word & 1 << bit != 0 {
+                    for (total, &part) in state.iter_mut().zip(&self.state) {
+                        *total ^= part;
+                    }
+                }
+                self.next_u64();
+            }
+        }
+        self.state = state;
+    }
+
+    //A uniform value in 0..bound, rejecting the few values which would bias the low end.
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        let bound = bound as u64;
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return (value % bound) as usize;
+            }
+        }
+    }
+
+    //A uniform value in [0, 1), from the top 53 bits.
+    pub(crate) fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(feature = "std")]
+use core::convert::TryFrom;
+
+use crate::card::{Card, Rank};
+use crate::hand_rank::{best_hand, best_hand_by, score, HandRank, Scoring};
+use crate::ranking::Ranking;
+#[cfg(feature = "std")]
+use crate::seven::evaluate_seven;
+
+/// The rules a game ranks its hands by.
+///
+/// Every rule set produces a [`HandRank`] where the greater rank is the better hand *under those
+/// rules*, so the same comparisons, sorting and winner selection work for all of them. Ranks
+/// produced under different rules must not be compared with each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RankingRules {
+    /// Standard high poker: aces high (or low in the wheel), straights and flushes count.
+    #[default]
+    High,
+    /// Ace-to-five lowball: the lowest hand wins, aces are always low and straights and flushes
+    /// are ignored, so 5-4-3-2-A (the wheel) is the best hand. Pairs still count against a hand.
+    AceToFive,
+    /// Deuce-to-seven lowball, as in 2-7 triple draw: the worst high hand wins. Aces are always
+    /// high and straights and flushes count against the hand, so the best hand is 7-5-4-3-2 of
+    /// mixed suits and A-2-3-4-5 is merely ace high.
+    DeuceToSeven,
+    /// Razz, seven card stud played for low: ranked like [`RankingRules::AceToFive`], with the
+    /// best five of the player's seven cards playing.
+    Razz,
+    /// The eight-or-better low of hi-lo games: ranked like [`RankingRules::AceToFive`], but only
+    /// five different ranks of eight or lower qualify. See [`RankingRules::qualifies`].
+    EightOrBetter,
+    /// Short deck (6+) Hold'em, played with the 36 cards from six to ace (see
+    /// [`Card::short_deck`]). A flush beats a full house, and A-6-7-8-9 is the lowest straight,
+    /// the ace playing below the six.
+    ///
+    /// Rooms differ on straights against three of a kind: with `trips_beat_straight` set, three
+    /// of a kind ranks above a straight, otherwise below it as in standard poker.
+    ShortDeck { trips_beat_straight: bool },
+}
+
+//The short deck category orders, from weakest to strongest.
+const SHORT_DECK: [Ranking; 10] = [
+    Ranking::HighCard,
+    Ranking::OnePair,
+    Ranking::TwoPair,
+    Ranking::ThreeOfAKind,
+    Ranking::Straight,
+    Ranking::FullHouse,
+    Ranking::Flush,
+    Ranking::FourOfAKind,
+    Ranking::StraightFlush,
+    Ranking::FiveOfAKind,
+];
+const SHORT_DECK_TRIPS_OVER_STRAIGHT: [Ranking; 10] = [
+    Ranking::HighCard,
+    Ranking::OnePair,
+    Ranking::TwoPair,
+    Ranking::Straight,
+    Ranking::ThreeOfAKind,
+    Ranking::FullHouse,
+    Ranking::Flush,
+    Ranking::FourOfAKind,
+    Ranking::StraightFlush,
+    Ranking::FiveOfAKind,
+];
+
+impl RankingRules {
+    fn scoring(&self) -> Scoring {
+        match self {
+            RankingRules::High => Scoring::HIGH,
+            RankingRules::AceToFive | RankingRules::Razz | RankingRules::EightOrBetter => Scoring {
+                ace_low: true,
+                straights_and_flushes: false,
+                wheel: None,
+                order: None,
+                lowball: true,
+            },
+            RankingRules::ShortDeck {
+                trips_beat_straight,
+            } => Scoring {
+                wheel: Some(Rank::Nine.value()),
+                order: Some(if *trips_beat_straight {
+                    &SHORT_DECK_TRIPS_OVER_STRAIGHT
+                } else {
+                    &SHORT_DECK
+                }),
+                ..Scoring::HIGH
+            },
+            RankingRules::DeuceToSeven => Scoring {
+                ace_low: false,
+                straights_and_flushes: true,
+                wheel: None,
+                order: None,
+                lowball: true,
+            },
+        }
+    }
+
+    /// Evaluates a five card hand under these rules. As with [`evaluate`], the cards may be in any
+    /// order, nothing is allocated and the cards are not validated.
+    ///
+    /// The [`HandRank::ranking`] of a lowball hand is its category as these rules see it, e.g. a
+    /// suited 5-4-3-2-A is a [`Ranking::HighCard`] in ace-to-five.
+    ///
+    /// [`evaluate`]: crate::evaluate
+    pub fn evaluate(&self, cards: &[Card]) -> HandRank {
+        score(cards, self.scoring())
+    }
+
+    /// Finds the best five of five to seven cards under these rules, as [`best_hand`] does for
+    /// high poker. This is how a Razz or short deck Hold'em hand is played.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than five or more than seven cards are given.
+    ///
+    /// [`best_hand`]: crate::best_hand
+    pub fn best_hand(&self, cards: &[Card]) -> (HandRank, [Card; 5]) {
+        match self {
+            RankingRules::High => best_hand(cards),
+            _ => best_hand_by(cards, |hand| self.evaluate(hand)),
+        }
+    }
+
+    //The rank of best_hand alone, which for seven cards under high rules needs no five cards
+    //picked out at all.
+    #[cfg(feature = "std")]
+    pub(crate) fn best_rank(&self, cards: &[Card]) -> HandRank {
+        match (self, <&[Card; 7]>::try_from(cards)) {
+            (RankingRules::High, Ok(seven)) => evaluate_seven(seven),
+            _ => self.best_hand(cards).0,
+        }
+    }
+
+    /// Whether a hand ranked under these rules may win at all. Only
+    /// [`RankingRules::EightOrBetter`] has a qualifier: no pair and nothing above an eight. Every
+    /// other rule set accepts every hand.
+    pub fn qualifies(&self, rank: HandRank) -> bool {
+        match self {
+            RankingRules::EightOrBetter => {
+                //Aces play low, so the first tie-break rank is the highest card.
+                rank.ranking() == Ranking::HighCard
+                    && rank
+                        .tie_break()
+                        .next()
+                        .is_some_and(|high| high <= Rank::Eight)
+            }
+            _ => true,
+        }
+    }
+}
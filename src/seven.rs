@@ -0,0 +1,106 @@
+use crate::card::{Card, Suit};
+use crate::card_set::CardSet;
+use crate::hand_rank::{sort_by_rank, HandRank};
+use crate::ranking::Ranking;
+use crate::tables::{FLUSHES, MULTISET_RANKS, OFFSETS};
+
+//Seven cards are ranked in one step rather than through their 21 five card subsets. Seven cards
+//hold at most one flush, and when they do no full house or four of a kind is possible, so the
+//flush suit's rank bits alone decide the hand. Otherwise only how many cards there are of each
+//rank matters, and every such multiset of ranks gets its own slot in a table through a perfect
+//hash: its position among all multisets in lexicographic order. Both tables are generated by
+//build.rs, holding exactly what best_hand would give.
+
+const RANKS: usize = 13;
+const CARDS: usize = 7;
+
+/// Evaluates seven cards at once, e.g. two hole cards plus a complete Hold'em board.
+///
+/// Gives the same [`HandRank`] as [`best_hand`], without trying the 21 ways of choosing five of
+/// the cards: a handful of table lookups, and no allocation. Only the rank is returned; use
+/// [`best_hand`] to find which five cards make it.
+///
+/// # Panics
+///
+/// The seven cards must be different. Debug builds panic if any card is repeated; release builds
+/// may panic or give a meaningless rank.
+///
+/// [`best_hand`]: crate::best_hand
+pub fn evaluate_seven(cards: &[Card; 7]) -> HandRank {
+    let set: CardSet = cards.iter().collect();
+    debug_assert_eq!(set.len(), CARDS, "the seven cards must be different");
+    for &suit in &Suit::ALL {
+        let ranks = set.suit_ranks(suit);
+        if ranks.count_ones() >= 5 {
+            return HandRank::from_u32(FLUSHES[ranks as usize]);
+        }
+    }
+    let mut counts = [0u8; RANKS];
+    for card in cards {
+        counts[(card.rank().value() - 2) as usize] += 1;
+    }
+    let mut index = 0;
+    let mut left = CARDS;
+    for (rank, &count) in counts.iter().enumerate() {
+        index += OFFSETS[rank][left][count as usize];
+        left -= count as usize;
+    }
+    HandRank::from_u32(MULTISET_RANKS[index as usize])
+}
+
+//best_hand for seven cards under high rules. The rank comes from evaluate_seven and the five cards
+//are picked to match it, rather than found by trying every subset. Among cards of the same rank
+//the ones given first are picked, which makes the same choice as best_hand_by.
+pub(crate) fn best_seven(cards: &[Card; 7]) -> (HandRank, [Card; 5]) {
+    let rank = evaluate_seven(cards);
+    let ranking = rank.ranking();
+    let set: CardSet = cards.iter().collect();
+    let flush = Suit::ALL
+        .iter()
+        .copied()
+        .find(|&suit| set.suit_ranks(suit).count_ones() >= 5);
+
+    //(rank value, number of cards of it) for every rank the hand is made of.
+    let mut wanted = [(0u8, 0u8); 5];
+    let mut tie_break = rank.tie_break();
+    match ranking {
+        Ranking::Straight | Ranking::StraightFlush => {
+            let high = tie_break
+                .next()
+                .expect("a straight has a high card")
+                .value();
+            for (value, slot) in (high - 4..=high).rev().zip(wanted.iter_mut()) {
+                //The wheel runs down to an ace.
+                *slot = (if value == 1 { 14 } else { value }, 1);
+            }
+        }
+        _ => {
+            let sizes: &[u8] = match ranking {
+                Ranking::FourOfAKind => &[4, 1],
+                Ranking::FullHouse => &[3, 2],
+                Ranking::ThreeOfAKind => &[3, 1, 1],
+                Ranking::TwoPair => &[2, 2, 1],
+                Ranking::OnePair => &[2, 1, 1, 1],
+                _ => &[1; 5],
+            };
+            for (slot, (rank, &size)) in wanted.iter_mut().zip(tie_break.zip(sizes)) {
+                *slot = (rank.value(), size);
+            }
+        }
+    }
+    let suited = matches!(ranking, Ranking::Flush | Ranking::StraightFlush);
+
+    let mut hand = [cards[0]; 5];
+    let mut len = 0;
+    for &(value, count) in &wanted {
+        let matching = cards
+            .iter()
+            .filter(|card| card.rank().value() == value && (!suited || Some(card.suit()) == flush));
+        for &card in matching.take(count as usize) {
+            hand[len] = card;
+            len += 1;
+        }
+    }
+    sort_by_rank(&mut hand);
+    (rank, hand)
+}
@@ -0,0 +1,84 @@
+use std::cmp::Reverse;
+
+use crate::error::PokerError;
+use crate::hand::{parse_showdown, Hand};
+use crate::hand_rank::HandRank;
+use crate::ranking::Ranking;
+
+/// Where one hand finished in a showdown, as returned by [`rank_hands`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Standing<T> {
+    /// The hand as it was passed in: the same `&str`, or the same [`Hand`], not a copy.
+    pub hand: T,
+    /// The hand's index among the hands passed in.
+    pub index: usize,
+    /// The hand's category, e.g. [`Ranking::FullHouse`].
+    pub ranking: Ranking,
+    /// The hand's full rank, category and kickers, which the standings are sorted by.
+    pub rank: HandRank,
+    /// The place the hand finished in, from 1. Tied hands share a place and the places after
+    /// them are skipped, so two hands tied for first are followed by third.
+    pub position: usize,
+}
+
+/// Ranks every hand, best first, rather than only picking the winners.
+///
+/// Hands which tie share a [position](Standing::position) and are listed in the order they were
+/// given. As with [`winning_hands`], each standing holds the very `&str` that was passed in.
+///
+/// Returns `None` if any hand fails to parse or the deal is impossible; see [`try_rank_hands`].
+///
+/// [`winning_hands`]: crate::winning_hands
+pub fn rank_hands<'a>(hands: &[&'a str]) -> Option<Vec<Standing<&'a str>>> {
+    try_rank_hands(hands).ok()
+}
+
+/// Fallible version of [`rank_hands`]. Errors are reported as by [`try_winning_hands`].
+///
+/// [`try_winning_hands`]: crate::try_winning_hands
+pub fn try_rank_hands<'a>(hands: &[&'a str]) -> Result<Vec<Standing<&'a str>>, PokerError> {
+    let mut parsed = Vec::new();
+    parse_showdown(hands, &mut parsed)?;
+
+    Ok(rank_parsed_hands(&parsed)
+        .into_iter()
+        .map(|standing| Standing {
+            hand: hands[standing.index],
+            index: standing.index,
+            ranking: standing.ranking,
+            rank: standing.rank,
+            position: standing.position,
+        })
+        .collect())
+}
+
+/// [`rank_hands`] for hands which are already parsed, so nothing is parsed twice.
+///
+/// Each [`Hand`] is valid on its own, but the hands are not checked against each other: a card
+/// held by two hands is ranked in both.
+pub fn rank_parsed_hands(hands: &[Hand]) -> Vec<Standing<&Hand>> {
+    let mut standings: Vec<Standing<&Hand>> = hands
+        .iter()
+        .enumerate()
+        .map(|(index, hand)| {
+            let rank = hand.rank();
+            Standing {
+                hand,
+                index,
+                ranking: rank.ranking(),
+                rank,
+                position: 0,
+            }
+        })
+        .collect();
+    //A stable sort keeps tied hands in the order they were given.
+    standings.sort_by_key(|standing| Reverse(standing.rank));
+    for i in 0..standings.len() {
+        standings[i].position = match i {
+            0 => 1,
+            _ if standings[i].rank == standings[i - 1].rank => standings[i - 1].position,
+            _ => i + 1,
+        };
+    }
+    standings
+}
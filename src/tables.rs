@@ -0,0 +1,3 @@
+//The lookup tables of evaluate_bits and evaluate_seven, generated by build.rs. Each entry is a
+//HandRank as HandRank::as_u32 gives it, or 0 where no hand lands.
+include!(concat!(env!("OUT_DIR"), "/tables.rs"));
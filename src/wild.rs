@@ -0,0 +1,264 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::card::{Card, Rank, Suit};
+use crate::error::{CardError, PokerError};
+use crate::hand::{check_deal_by, to_array, tokens_by, Hand};
+use crate::hand_rank::{best_indices, evaluate, HandRank};
+use crate::ranking::Ranking;
+
+/// A card from a deck which may hold jokers: either an ordinary card or a joker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WildCard {
+    Natural(Card),
+    Joker,
+}
+
+impl WildCard {
+    /// The ordinary card, or `None` for a joker.
+    pub fn natural(&self) -> Option<Card> {
+        match self {
+            WildCard::Natural(card) => Some(*card),
+            WildCard::Joker => None,
+        }
+    }
+
+    //Card::first_len, taking a joker written out in full as a single card.
+    fn first_len(cards: &str) -> usize {
+        match cards.get(..5) {
+            Some(word) if word.eq_ignore_ascii_case("joker") => 5,
+            _ => Card::first_len(cards),
+        }
+    }
+}
+
+impl From<Card> for WildCard {
+    fn from(card: Card) -> Self {
+        WildCard::Natural(card)
+    }
+}
+
+/// Accepts a joker as `Jk` or `XX` in either case, or as `Joker`, and anything else the way
+/// [`Card`] does.
+impl FromStr for WildCard {
+    type Err = CardError;
+
+    fn from_str(card: &str) -> Result<Self, Self::Err> {
+        if ["jk", "xx", "joker"]
+            .iter()
+            .any(|joker| joker.eq_ignore_ascii_case(card))
+        {
+            Ok(WildCard::Joker)
+        } else {
+            card.parse().map(WildCard::Natural)
+        }
+    }
+}
+
+//Jokers print as "Jk", or "Joker" with {:#}, next to the card's own Display.
+impl fmt::Display for WildCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WildCard::Natural(card) => card.fmt(f),
+            WildCard::Joker if f.alternate() => f.write_str("Joker"),
+            WildCard::Joker => f.write_str("Jk"),
+        }
+    }
+}
+
+/// What a joker may stand for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum JokerRule {
+    /// Any card at all.
+    #[default]
+    Wild,
+    /// The "bug": an ace, or any card which completes a straight or a flush.
+    Bug,
+}
+
+/// Which cards are wild, and how far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WildRules {
+    /// What jokers stand for.
+    pub joker: JokerRule,
+    /// A rank whose cards are fully wild, e.g. [`Rank::Two`] for deuces wild.
+    pub wild_rank: Option<Rank>,
+}
+
+//Steps through the non-decreasing sequences of rank indices, i.e. the multisets of ranks. Wild
+//cards are interchangeable, so "first wild a king, second an ace" and the reverse need only be
+//tried once.
+fn next_multiset(indices: &mut [usize]) -> bool {
+    for position in (0..indices.len()).rev() {
+        if indices[position] < Rank::ALL.len() - 1 {
+            indices[position] += 1;
+            let value = indices[position];
+            for later in &mut indices[position + 1..] {
+                *later = value;
+            }
+            return true;
+        }
+    }
+    false
+}
+
+/// Evaluates a five card hand holding wild cards, choosing the best card for each wild to stand
+/// for.
+///
+/// Returns the rank of the best hand together with the hand itself, where every wild card has
+/// been replaced by the card it became and every other card is left in place. Five of a kind is
+/// reachable here, and beats a straight flush. With a [`JokerRule::Bug`] a joker which does not
+/// complete a straight or a flush becomes an ace.
+///
+/// The substitute for a wild never duplicates another card of the hand when that can be avoided,
+/// but five of a kind will necessarily repeat a card. Like [`evaluate`], the cards are not
+/// validated.
+pub fn evaluate_wild(cards: &[WildCard; 5], rules: WildRules) -> (HandRank, [Card; 5]) {
+    //Positions of the fully wild cards and of the bugs; everything else is natural.
+    let mut hand = [Card::new(Rank::Ace, Suit::Spades); 5];
+    let (mut wilds, mut wild_len) = ([0usize; 5], 0);
+    let (mut bugs, mut bug_len) = ([0usize; 5], 0);
+    for (position, card) in cards.iter().enumerate() {
+        match card {
+            WildCard::Joker if rules.joker == JokerRule::Bug => {
+                bugs[bug_len] = position;
+                bug_len += 1;
+            }
+            WildCard::Natural(card) if Some(card.rank()) != rules.wild_rank => {
+                hand[position] = *card;
+            }
+            _ => {
+                wilds[wild_len] = position;
+                wild_len += 1;
+            }
+        }
+    }
+    let (wilds, bugs) = (&wilds[..wild_len], &bugs[..bug_len]);
+    let naturals = || {
+        (0..hand.len())
+            .filter(move |position| !wilds.contains(position) && !bugs.contains(position))
+    };
+
+    //Suits only matter for a flush, so the wilds either all take the suit of the naturals, or
+    //all take some other suit, which keeps them clear of the naturals for four or five of a kind.
+    let flush_suit = naturals()
+        .next()
+        .map_or(Suit::Spades, |first| hand[first].suit());
+    let off_suit = Suit::ALL
+        .iter()
+        .copied()
+        .find(|&suit| suit != flush_suit)
+        .expect("there is more than one suit");
+
+    let mut best: Option<(HandRank, [Card; 5])> = None;
+    let mut wild_ranks = [0usize; 5];
+    loop {
+        let mut bug_ranks = [0usize; 5];
+        loop {
+            for &suit in &[flush_suit, off_suit] {
+                let mut candidate = hand;
+                let chosen = wilds
+                    .iter()
+                    .zip(&wild_ranks)
+                    .chain(bugs.iter().zip(&bug_ranks));
+                for (&position, &rank) in chosen {
+                    candidate[position] = Card::new(Rank::ALL[rank], suit);
+                }
+                //A flush made of wilds must not repeat a card.
+                let repeated = (1..5).any(|i| candidate[..i].contains(&candidate[i]));
+                if suit == flush_suit && repeated {
+                    continue;
+                }
+                let rank = evaluate(&candidate);
+                let bugs_allowed = bugs
+                    .iter()
+                    .all(|&position| candidate[position].rank() == Rank::Ace)
+                    || matches!(
+                        rank.ranking(),
+                        Ranking::Straight | Ranking::Flush | Ranking::StraightFlush
+                    );
+                if bugs_allowed && best.is_none_or(|(best_rank, _)| rank > best_rank) {
+                    best = Some((rank, candidate));
+                }
+            }
+            if !next_multiset(&mut bug_ranks[..bugs.len()]) {
+                break;
+            }
+        }
+        if !next_multiset(&mut wild_ranks[..wilds.len()]) {
+            break;
+        }
+    }
+
+    let (rank, mut hand) = best.expect("a bug may always become an ace");
+    //Move wilds which repeat another card onto a suit which does not, where one is left. The suit
+    //of a flush is left alone; it never repeats.
+    if !matches!(rank.ranking(), Ranking::Flush | Ranking::StraightFlush) {
+        for &position in wilds.iter().chain(bugs) {
+            let taken = |card: Card, hand: &[Card; 5]| {
+                (0..5).any(|other| other != position && hand[other] == card)
+            };
+            if taken(hand[position], &hand) {
+                let rank = hand[position].rank();
+                if let Some(free) = Suit::ALL
+                    .iter()
+                    .map(|&suit| Card::new(rank, suit))
+                    .find(|&card| !taken(card, &hand))
+                {
+                    hand[position] = free;
+                }
+            }
+        }
+    }
+    (rank, hand)
+}
+
+//Parses a hand which may hold jokers, keeping the cards in the order they were written.
+fn parse_wild_cards(hand: &str) -> Result<[WildCard; Hand::SIZE], PokerError> {
+    let cards = tokens_by(hand, WildCard::first_len)
+        .enumerate()
+        .map(|(position, card)| {
+            card.parse().map_err(|source| PokerError {
+                hand: 0,
+                position,
+                source,
+            })
+        })
+        .collect::<Result<Vec<WildCard>, _>>()?;
+    to_array(&cards)
+}
+
+/// Like [`winning_hands`], for hands which may hold jokers (`Jk`, `XX` or `Joker`) or cards of a
+/// wild rank. Every wild plays as the card which makes its hand strongest.
+///
+/// [`winning_hands`]: crate::winning_hands
+pub fn winning_wild_hands<'a>(hands: &[&'a str], rules: WildRules) -> Option<Vec<&'a str>> {
+    try_winning_wild_hands(hands, rules).ok()
+}
+
+/// Fallible version of [`winning_wild_hands`]. Errors are reported as by
+/// [`try_winning_hands`]; jokers may appear any number of times, but no other card may be
+/// dealt twice.
+///
+/// [`try_winning_hands`]: crate::try_winning_hands
+pub fn try_winning_wild_hands<'a>(
+    hands: &[&'a str],
+    rules: WildRules,
+) -> Result<Vec<&'a str>, PokerError> {
+    let dealt = hands
+        .iter()
+        .enumerate()
+        .map(|(index, hand)| parse_wild_cards(hand).map_err(|error| error.in_hand(index)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    check_deal_by(dealt.iter().map(|cards| &cards[..]), WildCard::natural)?;
+
+    let ranks: Vec<HandRank> = dealt
+        .iter()
+        .map(|cards| evaluate_wild(cards, rules).0)
+        .collect();
+    Ok(best_indices(&ranks)
+        .into_iter()
+        .map(|index| hands[index])
+        .collect())
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "std")]
+
+use poker::{
+    equity, range_equity, try_winning_hands, try_winning_hands_batch, try_winning_hands_batch_with,
+    winning_hands, winning_hands_batch, winning_hands_with, winning_parsed_hands_batch, CardError,
+    Hand, Range, RankingRules,
+};
+
+mod common;
+use common::hole;
+
+fn showdowns() -> Vec<Vec<&'static str>> {
+    vec![
+        vec!["4S 5S 7H 8D JC", "2S 4C 7S 9H 10H", "3S 4S 5D 6H JH"],
+        vec!["4D 5S 6S 8D 3C", "2S 4C 7S 9H 10H", "3S 4S 5D 6H JH"],
+        vec!["4S 5H 4C 8D 4H", "10D JH QS KD AC"],
+        vec!["2S 8H 6S 8D JH", "4S 5H 4C 8C 5C"],
+        vec!["4S 5S 7H 8D XC", "2S 4C 7S 9H 10H"],
+        vec!["4S 5S 7H 8D JC", "4S 4C 7S 9H 10H"],
+        vec!["3S 4S 5D 6H JH", "3H 4H 5C 6C JD"],
+        vec![],
+    ]
+}
+
+#[test]
+fn test_batch_matches_one_showdown_at_a_time() {
+    let showdowns = showdowns();
+    let batch = winning_hands_batch(&showdowns);
+    assert_eq!(batch.len(), showdowns.len());
+    for (hands, winners) in showdowns.iter().zip(batch) {
+        assert_eq!(winners, winning_hands(hands));
+    }
+}
+
+#[test]
+fn test_batch_reports_each_error_in_place() {
+    let showdowns = showdowns();
+    let batch = try_winning_hands_batch(&showdowns);
+    for (hands, winners) in showdowns.iter().zip(&batch) {
+        assert_eq!(winners, &try_winning_hands(hands));
+    }
+    let error = batch[4].as_ref().unwrap_err();
+    assert_eq!((error.hand, error.position), (0, 4));
+    let error = batch[5].as_ref().unwrap_err();
+    assert!(matches!(
+        error.source,
+        CardError::CardSharedBetweenHands { hand: 0, .. }
+    ));
+    assert!(batch[3].is_ok());
+}
+
+#[test]
+fn test_batch_with_rules() {
+    let showdowns = [
+        ["2S 3D 4H 5C 7S", "2H 3C 4D 6S 7H"],
+        ["AS KS QS JS TS", "9H 9C 8D 8S 2H"],
+    ];
+    let batch = try_winning_hands_batch_with(&showdowns, RankingRules::DeuceToSeven);
+    for (hands, winners) in showdowns.iter().zip(batch) {
+        assert_eq!(
+            winners.ok(),
+            winning_hands_with(hands, RankingRules::DeuceToSeven)
+        );
+    }
+}
+
+#[test]
+fn test_parsed_batch_returns_the_same_hands() {
+    let showdowns: Vec<Vec<Hand>> = showdowns()
+        .into_iter()
+        .filter_map(|hands| {
+            hands
+                .iter()
+                .map(|hand| hand.parse())
+                .collect::<Result<Vec<Hand>, _>>()
+                .ok()
+        })
+        .collect();
+    let batch = winning_parsed_hands_batch(&showdowns);
+    assert_eq!(batch.len(), showdowns.len());
+    for (hands, winners) in showdowns.iter().zip(&batch) {
+        for winner in winners {
+            assert!(hands.iter().any(|hand| std::ptr::eq(hand, *winner)));
+        }
+    }
+    assert_eq!(batch[0], vec![&showdowns[0][0]]);
+    assert_eq!(batch[2], vec![&showdowns[2][1]]);
+    assert_eq!(batch[5].len(), 2);
+    assert!(batch.last().unwrap().is_empty());
+}
+
+#[test]
+fn test_many_showdowns_in_order() {
+    let trips = ["2S 3S 4S 5S 7D", "8H 8D 8C 2H 3H"];
+    let pair = ["9S 9D 2C 3D 4H", "2S 3S 4S 5S 7D"];
+    let showdowns: Vec<[&str; 2]> = (0..1_000)
+        .map(|i| if i % 3 == 0 { trips } else { pair })
+        .collect();
+    for (i, winners) in winning_hands_batch(&showdowns).into_iter().enumerate() {
+        let expected = if i % 3 == 0 { trips[1] } else { pair[0] };
+        assert_eq!(winners, Some(vec![expected]));
+    }
+}
+
+#[test]
+fn test_equity_over_many_chunks_is_pinned_by_its_seed() {
+    //More samples than a single chunk, so the result depends on how the streams are split. The
+    //figures are the same with and without the parallel feature.
+    let players = [hole("AH AS"), hole("KD KC")];
+    let results = equity(&players, &[], &[], 20_000, 7).unwrap();
+    assert_eq!(results, equity(&players, &[], &[], 20_000, 7).unwrap());
+    assert_eq!(results[0].samples, 20_000);
+    assert_eq!((results[0].wins, results[1].wins), (16_177, 3_735));
+    assert!((results[0].equity() - 0.82).abs() < 0.01, "{:?}", results);
+}
+
+#[test]
+fn test_range_equity_over_many_chunks_is_pinned_by_its_seed() {
+    let ranges: Vec<Range> = ["QQ+, AKs", "JJ-88, AQs"]
+        .iter()
+        .map(|range| range.parse().unwrap())
+        .collect();
+    let results = range_equity(&ranges, &[], 9_000, 3).unwrap();
+    assert_eq!(results, range_equity(&ranges, &[], 9_000, 3).unwrap());
+    assert!(!results[0].exhaustive);
+    assert_eq!(results[0].equity, 0.7481666666666666);
+}
@@ -0,0 +1,99 @@
+use std::convert::TryFrom;
+
+use poker::{Card, CardSet, Rank, Suit};
+
+fn card(card: &str) -> Card {
+    card.parse().expect("This card should parse")
+}
+
+fn set(cards: &str) -> CardSet {
+    cards.split_whitespace().map(card).collect()
+}
+
+#[test]
+fn test_card_index_round_trip() {
+    for (index, card) in Card::deck().enumerate() {
+        assert_eq!(card.index() as usize, index);
+        assert_eq!(Card::from_index(index as u8), Some(card));
+        assert_eq!(u8::from(card), index as u8);
+        assert_eq!(Card::try_from(index as u8).unwrap(), card);
+        assert_eq!(Card::new(card.rank(), card.suit()), card);
+    }
+    assert_eq!(Card::from_index(52), None);
+    assert!(Card::try_from(52).is_err());
+    assert_eq!(card("2h").index(), 0);
+    assert_eq!(card("Ac").index(), 51);
+}
+
+#[test]
+fn test_card_debug_shows_rank_and_suit() {
+    assert_eq!(
+        format!("{:?}", card("Qs")),
+        "Card { rank: Queen, suit: Spades }"
+    );
+}
+
+#[test]
+fn test_insert_remove_contains() {
+    let mut cards = CardSet::EMPTY;
+    assert!(cards.is_empty());
+    assert!(cards.insert(card("Ah")));
+    assert!(!cards.insert(card("Ah")));
+    assert!(cards.insert(card("2c")));
+    assert_eq!(cards.len(), 2);
+    assert!(cards.contains(card("Ah")));
+    assert!(!cards.contains(card("Ad")));
+    assert!(cards.remove(card("Ah")));
+    assert!(!cards.remove(card("Ah")));
+    assert_eq!(cards, set("2c"));
+}
+
+#[test]
+fn test_set_operations() {
+    let a = set("Ah Kh Qh");
+    let b = set("Qh Jh Th");
+    assert_eq!(a | b, set("Ah Kh Qh Jh Th"));
+    assert_eq!(a & b, set("Qh"));
+    assert_eq!(a - b, set("Ah Kh"));
+    assert_eq!(a.union(b), a | b);
+    assert_eq!(a.intersection(b), a & b);
+    assert_eq!(a.difference(b), a - b);
+    assert!(!a.is_disjoint(b));
+    assert!(a.is_disjoint(set("2c 3c")));
+    assert_eq!((!a).len(), 49);
+    assert_eq!(!CardSet::EMPTY, CardSet::FULL);
+    assert_eq!(CardSet::FULL.len(), 52);
+}
+
+#[test]
+fn test_iteration_in_deck_order() {
+    let cards = set("Ac 2h Kd 5s");
+    let listed: Vec<Card> = cards.iter().collect();
+    assert_eq!(listed, vec![card("2h"), card("Kd"), card("5s"), card("Ac")]);
+    assert_eq!(cards.iter().len(), 4);
+    let deck: Vec<Card> = CardSet::FULL.into_iter().collect();
+    assert_eq!(deck, Card::deck().collect::<Vec<_>>());
+    assert_eq!(cards.to_string(), "2h Kd 5s Ac");
+}
+
+#[test]
+fn test_suit_and_rank_masks() {
+    let hearts = CardSet::of_suit(Suit::Hearts);
+    assert_eq!(hearts.len(), 13);
+    assert!(hearts.iter().all(|card| card.suit() == Suit::Hearts));
+    let aces = CardSet::of_rank(Rank::Ace);
+    assert_eq!(aces, set("Ah Ad As Ac"));
+    assert_eq!(hearts & aces, set("Ah"));
+
+    let cards = set("2s 5s As Ks 3d");
+    assert_eq!(cards.suit_ranks(Suit::Spades), 0b1_1000_0000_1001);
+    assert_eq!(cards.suit_ranks(Suit::Diamonds), 0b10);
+    assert_eq!(cards.suit_ranks(Suit::Clubs), 0);
+}
+
+#[test]
+fn test_bits() {
+    assert_eq!(set("2h 3h").bits(), 0b11);
+    assert_eq!(CardSet::from_bits(u64::MAX), CardSet::FULL);
+    assert_eq!(CardSet::from(card("4h")), CardSet::from_bits(0b100));
+}
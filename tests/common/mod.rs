@@ -0,0 +1,20 @@
+//Helpers shared by the test files. Not every file uses every helper.
+#![allow(dead_code)]
+
+use poker::{Card, HoleCards, Range};
+
+pub fn cards(cards: &str) -> Vec<Card> {
+    cards
+        .split_whitespace()
+        .map(|card| card.parse().expect("This card should parse"))
+        .collect()
+}
+
+pub fn hole(hole: &str) -> HoleCards {
+    let cards = cards(hole);
+    [cards[0], cards[1]]
+}
+
+pub fn range(range: &str) -> Range {
+    range.parse().expect("This range should parse")
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "std")]
+
+use poker::{Card, CardError, Deck, WildCard};
+use std::collections::HashSet;
+
+mod common;
+use common::cards;
+
+fn shuffled(seed: u64) -> Deck {
+    let mut deck = Deck::standard();
+    deck.shuffle(seed);
+    deck
+}
+
+#[test]
+fn test_constructors() {
+    let deck = Deck::standard();
+    assert_eq!(deck.len(), 52);
+    assert_eq!(deck.remaining().collect::<HashSet<_>>().len(), 52);
+    assert_eq!(deck.remaining().next(), Some("2H".parse().unwrap()));
+
+    let deck = Deck::short();
+    assert_eq!(deck.len(), 36);
+    assert_eq!(deck.remaining().next(), Some("6H".parse().unwrap()));
+
+    let deck = Deck::with_jokers(2);
+    assert_eq!(deck.len(), 54);
+    assert_eq!(
+        deck.remaining()
+            .filter(|&card| card == WildCard::Joker)
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn test_same_seed_same_order() {
+    assert_eq!(shuffled(7), shuffled(7));
+    assert_ne!(shuffled(7), shuffled(8));
+    assert_ne!(shuffled(7), Deck::standard());
+}
+
+#[test]
+fn test_shuffle_keeps_every_card() {
+    let deck = shuffled(12345);
+    let standard: HashSet<Card> = Deck::standard().remaining().collect();
+    assert_eq!(deck.remaining().collect::<HashSet<_>>(), standard);
+}
+
+#[test]
+fn test_seeded_order_is_stable() {
+    // Pins the shuffle, so a seed recorded today replays the same deal in later releases.
+    let mut deck = shuffled(42);
+    assert_eq!(deck.deal(5).unwrap(), cards("QC 5H 5C 2S TC"));
+}
+
+#[test]
+fn test_deal_burn_and_remaining() {
+    let mut deck = Deck::standard();
+    assert_eq!(deck.deal(2).unwrap(), cards("2H 3H"));
+    assert_eq!(deck.burn().unwrap(), "4H".parse().unwrap());
+    assert_eq!(deck.deal(3).unwrap(), cards("5H 6H 7H"));
+    assert_eq!(deck.len(), 46);
+    assert_eq!(deck.remaining().next(), Some("8H".parse().unwrap()));
+    assert!(deck.remaining().all(|card| card != "2H".parse().unwrap()));
+}
+
+#[test]
+fn test_deal_too_many() {
+    let mut deck = Deck::short();
+    deck.deal(30).unwrap();
+    assert_eq!(
+        deck.deal(7),
+        Err(CardError::NotEnoughCards {
+            requested: 7,
+            remaining: 6
+        })
+    );
+    assert_eq!(deck.len(), 6);
+    deck.deal(6).unwrap();
+    assert!(deck.is_empty());
+    assert_eq!(
+        deck.burn(),
+        Err(CardError::NotEnoughCards {
+            requested: 1,
+            remaining: 0
+        })
+    );
+}
+
+#[test]
+fn test_remove_known_cards() {
+    let mut deck = Deck::standard();
+    deck.remove(&cards("AS KD 2H")).unwrap();
+    assert_eq!(deck.len(), 49);
+    assert_eq!(deck.remaining().next(), Some("3H".parse().unwrap()));
+    assert_eq!(
+        deck.remove(&cards("AS")),
+        Err(CardError::NotInDeck { card: "As".into() })
+    );
+
+    let mut deck = Deck::short();
+    assert!(deck.remove(&cards("5H")).is_err());
+
+    let mut deck = Deck::with_jokers(2);
+    deck.remove(&[WildCard::Joker, WildCard::Joker]).unwrap();
+    assert!(deck.remove(&[WildCard::Joker]).is_err());
+}
+
+#[test]
+fn test_remove_copies_and_stop_at_the_first_missing_card() {
+    let mut deck = Deck::new(cards("AS KD AS 2H"));
+    deck.remove(&cards("AS")).unwrap();
+    assert_eq!(deck.remaining().collect::<Vec<_>>(), cards("AS KD 2H"));
+
+    assert_eq!(
+        deck.remove(&cards("KD AS AS 2H")),
+        Err(CardError::NotInDeck { card: "As".into() })
+    );
+    assert_eq!(deck.remaining().collect::<Vec<_>>(), cards("2H"));
+}
+
+#[test]
+fn test_shuffle_after_removing() {
+    let mut lhs = Deck::standard();
+    let mut rhs = Deck::standard();
+    lhs.remove(&cards("AS AH")).unwrap();
+    rhs.remove(&cards("AS AH")).unwrap();
+    lhs.shuffle(3);
+    rhs.shuffle(3);
+    assert_eq!(lhs, rhs);
+    assert!(lhs
+        .remaining()
+        .all(|card| card != "AS".parse().unwrap() && card != "AH".parse().unwrap()));
+}
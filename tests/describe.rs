@@ -0,0 +1,169 @@
+#![cfg(feature = "std")]
+
+use poker::{evaluate, explain_winners, Decider, Deck, Hand, HandDescription, Rank, Ranking};
+
+fn describe(hand: &str) -> String {
+    Hand::parse(hand).unwrap().describe().to_string()
+}
+
+#[test]
+fn test_describe_every_category() {
+    assert_eq!(
+        describe("KH KD 5S 5C AD"),
+        "Two Pair, Kings and Fives, Ace kicker"
+    );
+    assert_eq!(
+        describe("9S 9H 9D 9C 3H"),
+        "Four of a Kind, Nines, Three kicker"
+    );
+    assert_eq!(
+        describe("KH KD KS 6C 6D"),
+        "Full House, Kings full of Sixes"
+    );
+    assert_eq!(
+        describe("2H 9H JH 6H AH"),
+        "Flush, Ace high, Jack, Nine, Six, Two kickers"
+    );
+    assert_eq!(describe("5H 6D 7S 8C 9D"), "Straight, Nine high");
+    assert_eq!(describe("AH 2D 3S 4C 5D"), "Straight, Five high");
+    assert_eq!(describe("TS JS QS KS AS"), "Straight Flush, Ace high");
+    assert_eq!(
+        describe("7H 7D 7S KC 2D"),
+        "Three of a Kind, Sevens, King, Two kickers"
+    );
+    assert_eq!(
+        describe("TH TD AS QC 4D"),
+        "One Pair, Tens, Ace, Queen, Four kickers"
+    );
+    assert_eq!(
+        describe("AH KD 9S 7C 3D"),
+        "High Card, Ace, King, Nine, Seven, Three kickers"
+    );
+}
+
+#[test]
+fn test_description_is_structured() {
+    let description = Hand::parse("KH KD 5S 5C AD").unwrap().describe();
+    assert_eq!(description.ranking, Ranking::TwoPair);
+    assert_eq!(description.made, vec![Rank::King, Rank::Five]);
+    assert_eq!(description.kickers, vec![Rank::Ace]);
+}
+
+#[test]
+fn test_description_lists_the_tie_break_ranks() {
+    for seed in 0..2_000 {
+        let mut deck = Deck::standard();
+        deck.shuffle(seed);
+        let cards = deck.deal(5).unwrap();
+        let rank = evaluate(&cards);
+        let description = HandDescription::of(&cards);
+        assert_eq!(description.ranking, rank.ranking(), "{:?}", cards);
+        let ranks: Vec<Rank> = description
+            .made
+            .iter()
+            .chain(&description.kickers)
+            .copied()
+            .collect();
+        assert_eq!(ranks, rank.tie_break().collect::<Vec<_>>(), "{:?}", cards);
+    }
+}
+
+#[test]
+fn test_won_on_kicker() {
+    let explanation = explain_winners(&["KH KD 5S 5C QD", "KS KC 5H 5D AD"]).unwrap();
+    assert_eq!(explanation.winners, vec![1]);
+    assert_eq!(explanation.runner_up, Some(0));
+    assert_eq!(
+        explanation.decider,
+        Decider::Kicker {
+            winner: Rank::Ace,
+            runner_up: Rank::Queen
+        }
+    );
+    assert_eq!(
+        explanation.to_string(),
+        "both Two Pair, Kings and Fives; won on kicker A vs Q"
+    );
+}
+
+#[test]
+fn test_won_on_category() {
+    let explanation = explain_winners(&["2H 9H JH 6H AH", "5H 6D 7S 8C 9D"]).unwrap();
+    assert_eq!(explanation.decider, Decider::Ranking);
+    assert_eq!(
+        explanation.to_string(),
+        "Flush, Ace high, Jack, Nine, Six, Two kickers beats Straight, Nine high"
+    );
+}
+
+#[test]
+fn test_won_on_made_ranks() {
+    let explanation = explain_winners(&["KH KD 5S 5C AD", "KS KC 6H 6D 2D"]).unwrap();
+    assert_eq!(explanation.winners, vec![1]);
+    assert_eq!(explanation.decider, Decider::Made);
+    assert_eq!(
+        explanation.to_string(),
+        "both Two Pair; Kings and Sixes beats Kings and Fives"
+    );
+
+    let explanation = explain_winners(&["AH 2D 3S 4C 5D", "2H 3D 4S 5C 6D"]).unwrap();
+    assert_eq!(
+        explanation.to_string(),
+        "both Straight; Six high beats Five high"
+    );
+}
+
+#[test]
+fn test_runner_up_is_the_best_loser() {
+    let explanation = explain_winners(&[
+        "2S 4C 7S 9H TH",
+        "3S 4S 5D 6H JH",
+        "3H 4H 5C 6C JD",
+        "AS AD 8C 8D 3C",
+    ])
+    .unwrap();
+    assert_eq!(explanation.winners, vec![3]);
+    assert_eq!(explanation.runner_up, Some(1));
+}
+
+#[test]
+fn test_split_pot() {
+    let explanation =
+        explain_winners(&["3S 4S 5D 6H JH", "3H 4H 5C 6C JD", "2S 4C 7S 9H TH"]).unwrap();
+    assert_eq!(explanation.winners, vec![0, 1]);
+    assert_eq!(explanation.decider, Decider::Split);
+    assert_eq!(
+        explanation.to_string(),
+        "split pot, 2 hands with High Card, Jack, Six, Five, Four, Three kickers"
+    );
+}
+
+#[test]
+fn test_uncontested() {
+    let explanation = explain_winners(&["4S 5S 7H 8D JC"]).unwrap();
+    assert_eq!(explanation.decider, Decider::Uncontested);
+    assert_eq!(explanation.runner_up, None);
+    assert_eq!(
+        explanation.to_string(),
+        "High Card, Jack, Eight, Seven, Five, Four kickers wins uncontested"
+    );
+}
+
+#[test]
+fn test_explanation_agrees_with_winning_hands() {
+    let hands = [
+        "4D 5S 6S 8D 3C",
+        "2S 4C 7S 9H 10H",
+        "3S 4S 5D 6H JH",
+        "3H 4H 5C 6C JD",
+    ];
+    let explanation = explain_winners(&hands).unwrap();
+    let winners: Vec<&str> = explanation.winners.iter().map(|&i| hands[i]).collect();
+    assert_eq!(Some(winners), poker::winning_hands(&hands));
+}
+
+#[test]
+fn test_invalid_hand() {
+    let error = explain_winners(&["4S 5S 7H 8D JC", "4S 2H 3H 6H 9H"]).unwrap_err();
+    assert_eq!(error.hand, 1);
+}
@@ -0,0 +1,231 @@
+#![cfg(feature = "std")]
+
+use poker::{
+    equity, equity_auto, exact_equity, holdem_winners, remaining_boards, Card, CardError, HoleCards,
+};
+
+mod common;
+use common::{cards, hole};
+
+#[test]
+fn test_aces_against_kings_preflop() {
+    let players = [hole("AH AS"), hole("KD KC")];
+    let results = equity(&players, &[], &[], 10_000, 1).unwrap();
+    // About 82% to 18%.
+    assert!((results[0].equity() - 0.82).abs() < 0.02, "{:?}", results);
+    assert!((results[1].equity() - 0.18).abs() < 0.02, "{:?}", results);
+    assert!(results[0].std_error() > 0.0 && results[0].std_error() < 0.01);
+    assert_eq!(results[0].samples, 10_000);
+}
+
+#[test]
+fn test_equities_add_up_to_one() {
+    let players = [hole("AH KH"), hole("QS QD"), hole("7C 6C")];
+    let results = equity(&players, &cards("2H 7H QC"), &[], 5_000, 9).unwrap();
+    let total: f64 = results.iter().map(|result| result.equity()).sum();
+    assert!((total - 1.0).abs() < 1e-9);
+    for result in &results {
+        assert!(result.win() + result.tie() <= 1.0);
+        assert!(result.equity() >= result.win());
+    }
+}
+
+#[test]
+fn test_same_seed_same_result() {
+    let players = [hole("AH KH"), hole("QS QD")];
+    let board = cards("2H 7H QC");
+    assert_eq!(
+        equity(&players, &board, &[], 1_000, 5).unwrap(),
+        equity(&players, &board, &[], 1_000, 5).unwrap()
+    );
+    assert_ne!(
+        equity(&players, &board, &[], 1_000, 5).unwrap(),
+        equity(&players, &board, &[], 1_000, 6).unwrap()
+    );
+}
+
+#[test]
+fn test_river_estimate_matches_enumeration() {
+    let players = [hole("AH KH"), hole("QS QD")];
+    let board = cards("2H 7H QC 3S");
+    let dead = cards("9H");
+
+    // Every river, settled by holdem_winners.
+    let known: Vec<Card> = players
+        .iter()
+        .flat_map(|hole| hole.iter().copied())
+        .chain(board.iter().copied())
+        .chain(dead.iter().copied())
+        .collect();
+    let mut share = 0.0;
+    let mut rivers = 0.0;
+    for river in Card::deck().filter(|card| !known.contains(card)) {
+        let mut full = board.clone();
+        full.push(river);
+        let winners = holdem_winners(&full, &players).unwrap();
+        if winners.contains(&0) {
+            share += 1.0 / winners.len() as f64;
+        }
+        rivers += 1.0;
+    }
+    let exact = share / rivers;
+
+    let results = equity(&players, &board, &dead, 10_000, 11).unwrap();
+    assert!(
+        (results[0].equity() - exact).abs() < 4.0 * results[0].std_error() + 1e-3,
+        "{} vs {}",
+        results[0].equity(),
+        exact
+    );
+}
+
+#[test]
+fn test_complete_board_is_settled() {
+    let players = [hole("AS 3D"), hole("AC 4C"), hole("QS JD")];
+    let results = equity(&players, &cards("2H 2D 7C 8S KH"), &[], 10, 0).unwrap();
+    assert_eq!(results[0].ties, 10);
+    assert_eq!(results[0].equity(), 0.5);
+    assert_eq!(results[2].equity(), 0.0);
+    assert_eq!(results[0].std_error(), 0.0);
+}
+
+#[test]
+fn test_errors() {
+    let players = [hole("AH AS"), hole("KD KC")];
+    let error = equity(&players, &cards("AH 2C 3C"), &[], 10, 0).unwrap_err();
+    assert_eq!(error.hand, 2);
+    assert!(matches!(
+        error.source,
+        CardError::CardSharedBetweenHands { hand: 0, .. }
+    ));
+
+    let error = equity(&players, &cards("2C 3C 4C 5C 6C 7C"), &[], 10, 0).unwrap_err();
+    assert_eq!(error.hand, 2);
+    assert!(matches!(error.source, CardError::WrongHandSize { .. }));
+
+    let error = equity(&players, &[], &cards("KD"), 10, 0).unwrap_err();
+    assert_eq!(error.hand, 3);
+}
+
+#[test]
+fn test_too_many_dead_cards() {
+    let players = [hole("AH AS")];
+    let dead: Vec<Card> = Card::deck()
+        .filter(|card| !players[0].contains(card))
+        .take(48)
+        .collect();
+    let error = equity(&players, &[], &dead, 10, 0).unwrap_err();
+    assert_eq!(error.hand, 2);
+    assert_eq!(
+        error.source,
+        CardError::NotEnoughCards {
+            requested: 5,
+            remaining: 2
+        }
+    );
+    let error = exact_equity(&players, &[], &dead).unwrap_err();
+    assert!(matches!(error.source, CardError::NotEnoughCards { .. }));
+    let error = equity_auto(&players, &[], &dead, 1_000, 0).unwrap_err();
+    assert!(matches!(error.source, CardError::NotEnoughCards { .. }));
+
+    //Exactly enough is fine: one board left to deal.
+    let results = exact_equity(&players, &[], &dead[..45]).unwrap();
+    assert_eq!(results[0].samples, 1);
+}
+
+/// Each player's share of the pot over every river, settled by holdem_winners, as a fraction.
+fn enumerate_rivers(players: &[HoleCards], board: &[Card]) -> Vec<(u64, u64)> {
+    let known: Vec<Card> = players
+        .iter()
+        .flat_map(|hole| hole.iter().copied())
+        .chain(board.iter().copied())
+        .collect();
+    // Shares in sixths, enough for splits two and three ways.
+    let mut shares = vec![0; players.len()];
+    let mut rivers = 0;
+    for river in Card::deck().filter(|card| !known.contains(card)) {
+        let mut full = board.to_vec();
+        full.push(river);
+        let winners = holdem_winners(&full, players).unwrap();
+        for &winner in &winners {
+            shares[winner] += 6 / winners.len() as u64;
+        }
+        rivers += 1;
+    }
+    shares
+        .into_iter()
+        .map(|share| (share, rivers * 6))
+        .collect()
+}
+
+fn same_fraction((a, b): (u64, u64), (c, d): (u64, u64)) -> bool {
+    a * d == b * c
+}
+
+#[test]
+fn test_exact_turn_matches_enumeration() {
+    let players = [hole("AH KH"), hole("QS QD"), hole("7C 7D")];
+    let board = cards("2H 7H QC 3S");
+    let results = exact_equity(&players, &board, &[]).unwrap();
+    for (result, expected) in results.iter().zip(enumerate_rivers(&players, &board)) {
+        assert!(result.exhaustive);
+        assert_eq!(result.samples, 44 - 2);
+        assert_eq!(result.std_error(), 0.0);
+        assert!(same_fraction(result.pot_share(), expected), "{:?}", result);
+    }
+}
+
+#[test]
+fn test_exact_flop_deals_every_board_once() {
+    let players = [hole("AH KH"), hole("QS QD")];
+    let results = exact_equity(&players, &cards("2H 7H QC"), &[]).unwrap();
+    assert_eq!(results[0].samples, 990);
+    let (lhs, denominator) = results[0].pot_share();
+    let (rhs, _) = results[1].pot_share();
+    assert_eq!(lhs + rhs, denominator);
+    assert_eq!(
+        results[0].wins + results[1].wins + results[0].ties,
+        results[0].samples
+    );
+}
+
+#[test]
+fn test_exact_three_way_split() {
+    let players = [hole("2S 3D"), hole("9C 8C"), hole("AS AD")];
+    let results = exact_equity(&players, &cards("TH JH QH KH AH"), &[]).unwrap();
+    for result in &results {
+        assert_eq!(result.ties, 1);
+        assert!(same_fraction(result.pot_share(), (1, 3)));
+    }
+}
+
+#[test]
+fn test_remaining_boards() {
+    assert_eq!(remaining_boards(2, 0, 0), 1_712_304);
+    assert_eq!(remaining_boards(2, 3, 0), 990);
+    assert_eq!(remaining_boards(2, 4, 0), 44);
+    assert_eq!(remaining_boards(3, 4, 1), 41);
+    assert_eq!(remaining_boards(2, 5, 0), 1);
+}
+
+#[test]
+fn test_auto_switches_between_exact_and_sampled() {
+    let players = [hole("AH KH"), hole("QS QD")];
+    let turn = cards("2H 7H QC 3S");
+    let results = equity_auto(&players, &turn, &[], 1_000, 0).unwrap();
+    assert!(results[0].exhaustive);
+    assert_eq!(results, exact_equity(&players, &turn, &[]).unwrap());
+
+    let results = equity_auto(&players, &[], &[], 1_000, 0).unwrap();
+    assert!(!results[0].exhaustive);
+    assert_eq!(results[0].samples, 1_000);
+}
+
+#[test]
+#[ignore]
+fn test_exact_preflop() {
+    let players = [hole("AH AS"), hole("KD KC")];
+    let results = exact_equity(&players, &[], &[]).unwrap();
+    assert_eq!(results[0].samples, 1_712_304);
+    assert!((results[0].equity() - 0.82).abs() < 0.01);
+}
@@ -0,0 +1,116 @@
+use poker::{Card, CardError, Hand, Rank, Ranking, Suit};
+use std::convert::TryFrom;
+
+#[test]
+fn test_parsed_hand_is_sorted_from_highest_rank() {
+    let hand = Hand::parse("4S JC 7H 10D 5S").expect("This hand should parse");
+    let ranks: Vec<Rank> = hand.cards().iter().map(Card::rank).collect();
+    assert_eq!(
+        ranks,
+        vec![Rank::Jack, Rank::Ten, Rank::Seven, Rank::Five, Rank::Four]
+    )
+}
+
+#[test]
+fn test_hand_built_from_cards_matches_parsed_hand() {
+    let hand = Hand::new([
+        Card::new(Rank::Two, Suit::Hearts),
+        Card::new(Rank::King, Suit::Spades),
+        Card::new(Rank::King, Suit::Hearts),
+        Card::new(Rank::Nine, Suit::Clubs),
+        Card::new(Rank::Two, Suit::Diamonds),
+    ])
+    .expect("This hand should be valid");
+    assert_eq!(hand, Hand::parse("2H KS KH 9C 2D").unwrap());
+    assert_eq!(hand.ranking(), Ranking::TwoPair)
+}
+
+#[test]
+fn test_card_accessors() {
+    let card = Card::new(Rank::Queen, Suit::Diamonds);
+    assert_eq!(card.rank(), Rank::Queen);
+    assert_eq!(card.suit(), Suit::Diamonds)
+}
+
+#[test]
+fn test_ranking_of_each_category() {
+    let cases = [
+        ("4S 5S 7H 8D JC", Ranking::HighCard),
+        ("2S 4H 6S 4D JH", Ranking::OnePair),
+        ("2S 8H 2D 8D 3H", Ranking::TwoPair),
+        ("4S 5H 4C 8S 4H", Ranking::ThreeOfAKind),
+        ("3S 4D 2S 6D 5C", Ranking::Straight),
+        ("2S 4S 5S 6S 7S", Ranking::Flush),
+        ("4S 5C 4C 5D 4H", Ranking::FullHouse),
+        ("3S 3H 2S 3D 3C", Ranking::FourOfAKind),
+        ("7S 8S 9S 6S 10S", Ranking::StraightFlush),
+    ];
+    for (hand, ranking) in cases.iter() {
+        assert_eq!(Hand::parse(hand).unwrap().ranking(), *ranking, "{}", hand)
+    }
+}
+
+#[test]
+fn test_wheel_is_five_high_and_leaves_cards_untouched() {
+    let hand = Hand::parse("4D AH 3S 2D 5C").unwrap();
+    let cards = hand.cards().to_vec();
+    assert_eq!(Ranking::determine_ranking(&cards), Ranking::Straight);
+    assert_eq!(
+        hand.rank().tie_break().collect::<Vec<Rank>>(),
+        vec![Rank::Five]
+    );
+    assert_eq!(cards[0], Card::new(Rank::Ace, Suit::Hearts));
+    assert_eq!(hand.cards(), cards.as_slice())
+}
+
+#[test]
+fn test_every_rank_has_a_distinct_value() {
+    let hand = Hand::parse("AS 2S 3S 4S 5S").unwrap();
+    let values: Vec<u8> = hand
+        .cards()
+        .iter()
+        .map(|card| card.rank().value())
+        .collect();
+    assert_eq!(values, vec![14, 5, 4, 3, 2])
+}
+
+#[test]
+fn test_hand_must_hold_five_cards() {
+    let error = Hand::parse("AS KS QS JS").unwrap_err();
+    assert_eq!(
+        error.source,
+        CardError::WrongHandSize {
+            expected: 5,
+            found: 4
+        }
+    );
+    let cards = [Card::new(Rank::Ace, Suit::Spades); 1];
+    assert!(Hand::try_from(&cards[..]).is_err())
+}
+
+#[test]
+fn test_hand_rejects_duplicate_cards() {
+    let ace = Card::new(Rank::Ace, Suit::Spades);
+    let king = Card::new(Rank::King, Suit::Spades);
+    let error = Hand::new([ace, king, king, Card::new(Rank::Two, Suit::Clubs), ace]).unwrap_err();
+    assert_eq!(error.position, 2);
+    assert_eq!(
+        error.source,
+        CardError::DuplicateCard {
+            card: king,
+            first: 1
+        }
+    )
+}
+
+#[test]
+fn test_same_cards_in_any_order_make_equal_hands() {
+    use std::collections::HashSet;
+
+    let first = Hand::parse("KH KS 2D 2C AS").unwrap();
+    let second = Hand::parse("KS KH 2C 2D AS").unwrap();
+    assert_eq!(first, second);
+    assert_eq!(first.cards(), second.cards());
+    let hands: HashSet<Hand> = vec![first, second].into_iter().collect();
+    assert_eq!(hands.len(), 1);
+}
@@ -0,0 +1,68 @@
+use poker::{evaluate, Hand, HandRank, Rank, Ranking};
+use std::collections::{BTreeSet, HashMap};
+
+fn rank(hand: &str) -> HandRank {
+    Hand::parse(hand).expect("This hand should parse").rank()
+}
+
+#[test]
+fn test_evaluate_ignores_card_order() {
+    let hand = Hand::parse("KS 2H KH 9C 2D").unwrap();
+    let mut cards = hand.cards().to_vec();
+    cards.reverse();
+    assert_eq!(evaluate(&cards), hand.rank())
+}
+
+#[test]
+fn test_categories_compare_in_a_single_cmp() {
+    let ordered = [
+        "4S 5S 7H 8D JC",
+        "2S 4H 6S 4D JH",
+        "2S 8H 2D 8D 3H",
+        "4S 5H 4C 8S 4H",
+        "3S 4D 2S 6D 5C",
+        "2S 4S 5S 6S 7S",
+        "4S 5C 4C 5D 4H",
+        "3S 3H 2S 3D 3C",
+        "7S 8S 9S 6S 10S",
+    ];
+    let mut ranks: Vec<HandRank> = ordered.iter().rev().map(|hand| rank(hand)).collect();
+    ranks.sort();
+    let expected: Vec<HandRank> = ordered.iter().map(|hand| rank(hand)).collect();
+    assert_eq!(ranks, expected)
+}
+
+#[test]
+fn test_equal_hands_deduplicate() {
+    let ranks: BTreeSet<HandRank> = ["3S 4S 5D 6H JH", "3H 4H 5C 6C JD", "2S 4C 7S 9H 10H"]
+        .iter()
+        .map(|hand| rank(hand))
+        .collect();
+    assert_eq!(ranks.len(), 2)
+}
+
+#[test]
+fn test_hand_rank_as_map_key() {
+    let mut wins: HashMap<HandRank, &str> = HashMap::new();
+    wins.insert(rank("4H 4S 4D 9S 9D"), "full house");
+    assert_eq!(wins.get(&rank("4C 4S 4D 9H 9D")), Some(&"full house"))
+}
+
+#[test]
+fn test_category_and_tie_break_are_exposed() {
+    let full_house = rank("5H 5S KD KS KC");
+    assert_eq!(full_house.ranking(), Ranking::FullHouse);
+    assert_eq!(
+        full_house.tie_break().collect::<Vec<Rank>>(),
+        vec![Rank::King, Rank::Five]
+    );
+
+    let wheel = rank("4D AH 3S 2D 5C");
+    assert_eq!(wheel.ranking(), Ranking::Straight);
+    assert_eq!(wheel.tie_break().collect::<Vec<Rank>>(), vec![Rank::Five])
+}
+
+#[test]
+fn test_stronger_hand_has_larger_packed_value() {
+    assert!(rank("4S 5H 5S 5D 5C").as_u32() > rank("2S 2H 2C 8D 2D").as_u32())
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "std")]
+
+use poker::{best_hand, evaluate, holdem_winners, Card, CardError, Ranking};
+
+mod common;
+use common::{cards, hole};
+
+#[test]
+fn test_best_hand_of_five_is_the_hand_itself() {
+    let hand = cards("4S 5S 7H 8D JC");
+    let (rank, best) = best_hand(&hand);
+    assert_eq!(rank, evaluate(&hand));
+    assert_eq!(best.to_vec(), cards("JC 8D 7H 5S 4S"));
+}
+
+#[test]
+fn test_best_hand_picks_the_strongest_five_of_seven() {
+    let (rank, best) = best_hand(&cards("AH KH 2C QH 7D JH TH"));
+    assert_eq!(rank.ranking(), Ranking::StraightFlush);
+    assert_eq!(best.to_vec(), cards("AH KH QH JH TH"));
+}
+
+#[test]
+fn test_best_hand_of_six() {
+    let (rank, best) = best_hand(&cards("9S 9H 9D 4C 4S 2H"));
+    assert_eq!(rank.ranking(), Ranking::FullHouse);
+    assert_eq!(best.to_vec(), cards("9S 9H 9D 4C 4S"));
+}
+
+#[test]
+fn test_best_hand_finds_the_wheel() {
+    let (rank, _) = best_hand(&cards("AS 2D 3C 4H 5S KD KC"));
+    assert_eq!(rank.ranking(), Ranking::Straight);
+}
+
+#[test]
+fn test_best_hand_agrees_with_every_subset() {
+    let seven = cards("7S 7H 8D 9C TS JS 7D");
+    let mut strongest = None;
+    for skip_a in 0..seven.len() {
+        for skip_b in skip_a + 1..seven.len() {
+            let hand: Vec<Card> = seven
+                .iter()
+                .enumerate()
+                .filter(|&(index, _)| index != skip_a && index != skip_b)
+                .map(|(_, card)| *card)
+                .collect();
+            strongest = strongest.max(Some(evaluate(&hand)));
+        }
+    }
+    assert_eq!(Some(best_hand(&seven).0), strongest);
+}
+
+#[test]
+#[should_panic]
+fn test_best_hand_rejects_four_cards() {
+    best_hand(&cards("AS KS QS JS"));
+}
+
+#[test]
+#[should_panic]
+fn test_best_hand_rejects_eight_cards() {
+    best_hand(&cards("AS KS QS JS TS 9S 8S 7S"));
+}
+
+#[test]
+fn test_holdem_single_winner() {
+    let board = cards("2H 7D 9C JS KH");
+    let players = [hole("AS AD"), hole("KS QD"), hole("3C 4C")];
+    assert_eq!(holdem_winners(&board, &players), Ok(vec![0]));
+}
+
+#[test]
+fn test_holdem_kicker_decides() {
+    let board = cards("AH 7D 9C JS 2H");
+    let players = [hole("AS QD"), hole("AD KC")];
+    assert_eq!(holdem_winners(&board, &players), Ok(vec![1]));
+}
+
+#[test]
+fn test_holdem_split_pot_when_board_plays() {
+    let board = cards("TH JH QH KH AH");
+    let players = [hole("2S 3D"), hole("9H 8C"), hole("AS AD")];
+    assert_eq!(holdem_winners(&board, &players), Ok(vec![0, 1, 2]));
+}
+
+#[test]
+fn test_holdem_split_pot_between_some_players() {
+    let board = cards("2H 2D 7C 8S KH");
+    let players = [hole("AS 3D"), hole("AC 4C"), hole("QS JD")];
+    assert_eq!(holdem_winners(&board, &players), Ok(vec![0, 1]));
+}
+
+#[test]
+fn test_holdem_rejects_short_board() {
+    let board = cards("2H 7D 9C JS");
+    let error = holdem_winners(&board, &[hole("AS AD"), hole("KS KD")]).unwrap_err();
+    assert_eq!(error.hand, 2);
+    assert_eq!(
+        error.source,
+        CardError::WrongHandSize {
+            expected: 5,
+            found: 4
+        }
+    );
+}
+
+#[test]
+fn test_holdem_rejects_card_on_board_and_in_hand() {
+    let board = cards("2H 7D 9C JS AS");
+    let error = holdem_winners(&board, &[hole("AS AD"), hole("KS KD")]).unwrap_err();
+    assert_eq!(error.hand, 2);
+    assert_eq!(error.position, 4);
+    assert_eq!(
+        error.source,
+        CardError::CardSharedBetweenHands {
+            card: "AS".parse().unwrap(),
+            hand: 0,
+            position: 0
+        }
+    );
+}
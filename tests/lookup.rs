@@ -0,0 +1,73 @@
+use poker::{evaluate, evaluate_bits, Card, Hand, Rank, Ranking, Suit};
+
+fn bits(cards: &[Card]) -> [u32; 5] {
+    [
+        cards[0].to_bits(),
+        cards[1].to_bits(),
+        cards[2].to_bits(),
+        cards[3].to_bits(),
+        cards[4].to_bits(),
+    ]
+}
+
+#[test]
+fn test_card_bits() {
+    //The king of diamonds and the five of spades, as in Cactus Kev's own description.
+    assert_eq!(Card::new(Rank::King, Suit::Diamonds).to_bits(), 0x0800_4B25);
+    assert_eq!(Card::new(Rank::Five, Suit::Spades).to_bits(), 0x0008_1307);
+    assert_eq!(Card::new(Rank::Jack, Suit::Clubs).to_bits(), 0x0200_891D);
+}
+
+#[test]
+fn test_examples() {
+    for hand in &[
+        "4S 5S 7H 8D JC",
+        "2S 2H 7S 9H TH",
+        "KH KD 5S 5C AD",
+        "7H 7D 7S KC 2D",
+        "AH 2D 3S 4C 5D",
+        "TH JD QS KC AD",
+        "2H 9H JH 6H AH",
+        "KH KD KS 6C 6D",
+        "9S 9H 9D 9C 3H",
+        "AH 2H 3H 4H 5H",
+        "TS JS QS KS AS",
+    ] {
+        let hand = Hand::parse(hand).unwrap();
+        assert_eq!(evaluate_bits(&bits(hand.cards())), hand.rank(), "{}", hand);
+    }
+}
+
+#[test]
+fn test_five_of_a_kind() {
+    let card = Card::new(Rank::Queen, Suit::Hearts);
+    let cards = [card; 5];
+    let rank = evaluate_bits(&bits(&cards));
+    assert_eq!(rank.ranking(), Ranking::FiveOfAKind);
+    assert_eq!(rank, evaluate(&cards));
+}
+
+#[test]
+fn test_agrees_with_evaluate_on_every_hand() {
+    let deck: Vec<Card> = Card::deck().collect();
+    let encoded: Vec<u32> = deck.iter().map(Card::to_bits).collect();
+    let mut hands = 0;
+    for a in 0..52 {
+        for b in a + 1..52 {
+            for c in b + 1..52 {
+                for d in c + 1..52 {
+                    for e in d + 1..52 {
+                        let cards = [deck[a], deck[b], deck[c], deck[d], deck[e]];
+                        let rank = evaluate_bits(&[
+                            encoded[a], encoded[b], encoded[c], encoded[d], encoded[e],
+                        ]);
+                        assert_eq!(rank, evaluate(&cards), "{:?}", cards);
+                        assert_eq!(rank.ranking(), Ranking::determine_ranking(&cards));
+                        hands += 1;
+                    }
+                }
+            }
+        }
+    }
+    assert_eq!(hands, 2_598_960);
+}
@@ -0,0 +1,104 @@
+//Everything here is part of the core which builds without the std feature, so these tests run
+//with and without it.
+use std::convert::TryFrom;
+
+use poker::{
+    best_hand, evaluate, evaluate_bits, evaluate_seven, Card, CardError, CardSet, ErrorText, Hand,
+    HandRank, Rank, Ranking, RankingRules, Suit,
+};
+
+fn cards<const N: usize>(cards: &str) -> [Card; N] {
+    let mut parsed = [Card::new(Rank::Two, Suit::Hearts); N];
+    for (slot, card) in parsed.iter_mut().zip(cards.split_whitespace()) {
+        *slot = card.parse().expect("This card should parse");
+    }
+    parsed
+}
+
+#[test]
+fn test_parse_into_a_fixed_hand() {
+    let hand = Hand::parse("4S 5S 7H 8D JC").unwrap();
+    let parsed: [Card; 5] = cards("JC 8D 7H 5S 4S");
+    assert_eq!(hand.cards(), &parsed[..]);
+    assert_eq!(hand.rank(), evaluate(&parsed));
+    assert_eq!(hand.ranking(), Ranking::HighCard);
+}
+
+#[test]
+fn test_hand_size_is_checked_without_collecting() {
+    let error = Hand::parse("4S 5S 7H 8D JC 2H").unwrap_err();
+    assert_eq!(error.position, 5);
+    assert_eq!(
+        error.source,
+        CardError::WrongHandSize {
+            expected: 5,
+            found: 6
+        }
+    );
+    let error = Hand::parse("4S 5S 7H").unwrap_err();
+    assert_eq!(error.position, 3);
+    //A bad card is reported before the count, even past the fifth card.
+    let error = Hand::parse("4S 5S 7H 8D JC 2H 1X").unwrap_err();
+    assert_eq!(error.position, 6);
+    assert!(matches!(error.source, CardError::IncorrectCard { .. }));
+}
+
+#[test]
+fn test_hand_does_not_depend_on_card_order() {
+    let first = Hand::parse("KH 2C KS 2D 9C").unwrap();
+    let second = Hand::parse("2D KS 9C 2C KH").unwrap();
+    assert_eq!(first, second);
+    assert_eq!(first.cards(), second.cards());
+    let ranks: [Rank; 5] = [Rank::King, Rank::King, Rank::Nine, Rank::Two, Rank::Two];
+    for (card, rank) in first.cards().iter().zip(&ranks) {
+        assert_eq!(card.rank(), *rank);
+    }
+}
+
+#[test]
+fn test_evaluators_agree() {
+    let hand: [Card; 5] = cards("TH JH QH KH AH");
+    let bits = [
+        hand[0].to_bits(),
+        hand[1].to_bits(),
+        hand[2].to_bits(),
+        hand[3].to_bits(),
+        hand[4].to_bits(),
+    ];
+    assert_eq!(evaluate_bits(&bits), evaluate(&hand));
+    assert_eq!(evaluate(&hand).ranking(), Ranking::StraightFlush);
+
+    let seven: [Card; 7] = cards("2C 2D KS KD 7H 7C 2S");
+    let (rank, best) = best_hand(&seven);
+    assert_eq!(evaluate_seven(&seven), rank);
+    assert_eq!(rank.ranking(), Ranking::FullHouse);
+    assert_eq!(best, cards::<5>("KS KD 2C 2D 2S"));
+
+    let low: [Card; 5] = cards("7S 5D 4H 3C 2S");
+    assert!(RankingRules::DeuceToSeven.evaluate(&low) > RankingRules::DeuceToSeven.evaluate(&hand));
+}
+
+#[test]
+fn test_card_sets_and_ranks_need_no_allocation() {
+    let board: CardSet = cards::<3>("2C 2D KS").iter().collect();
+    assert_eq!(board.len(), 3);
+    assert!(board.contains(Card::try_from(39).unwrap()));
+    let ranks: [Rank; 2] = {
+        let rank: HandRank = evaluate(&cards::<5>("KS KD 2C 2D 9H"));
+        let mut tie_break = rank.tie_break();
+        [tie_break.next().unwrap(), tie_break.next().unwrap()]
+    };
+    assert_eq!(ranks, [Rank::King, Rank::Two]);
+}
+
+#[test]
+fn test_error_quotes_the_bad_card() {
+    let error = "1X".parse::<Card>().unwrap_err();
+    let CardError::IncorrectCard { card } = &error else {
+        panic!("expected IncorrectCard, got {:?}", error);
+    };
+    assert_eq!(card.as_str(), "1");
+    assert_eq!(*card, ErrorText::from("1"));
+    let error = Card::try_from(52).unwrap_err();
+    assert!(error.to_string().starts_with("52 does not translate"));
+}
@@ -0,0 +1,133 @@
+#![cfg(feature = "std")]
+
+use poker::{Card, CardError, CardFormat, Hand, Rank, Suit};
+use std::convert::TryFrom;
+
+#[test]
+fn test_ten_parses_as_t_or_10() {
+    assert_eq!("T".parse::<Rank>(), Ok(Rank::Ten));
+    assert_eq!("10".parse::<Rank>(), Ok(Rank::Ten));
+    assert_eq!("Th".parse::<Card>(), "10H".parse::<Card>())
+}
+
+#[test]
+fn test_ranks_and_suits_parse_in_either_case() {
+    assert_eq!("q".parse::<Rank>(), Ok(Rank::Queen));
+    assert_eq!(Suit::try_from('d'), Ok(Suit::Diamonds));
+    assert_eq!(Suit::try_from('D'), Ok(Suit::Diamonds));
+    assert_eq!(
+        "kS".parse::<Card>(),
+        Ok(Card::new(Rank::King, Suit::Spades))
+    )
+}
+
+#[test]
+fn test_unicode_suits() {
+    for (symbol, suit) in [
+        ('♠', Suit::Spades),
+        ('♥', Suit::Hearts),
+        ('♦', Suit::Diamonds),
+        ('♣', Suit::Clubs),
+    ]
+    .iter()
+    {
+        assert_eq!(Suit::try_from(*symbol), Ok(*suit));
+    }
+    assert_eq!("A♥".parse::<Card>(), Ok(Card::new(Rank::Ace, Suit::Hearts)))
+}
+
+#[test]
+fn test_long_card_notation() {
+    assert_eq!(
+        "Ace of Hearts".parse::<Card>(),
+        Ok(Card::new(Rank::Ace, Suit::Hearts))
+    );
+    assert_eq!(
+        "ten of clubs".parse::<Card>(),
+        Ok(Card::new(Rank::Ten, Suit::Clubs))
+    )
+}
+
+#[test]
+fn test_compact_hand_notation() {
+    let spaced = Hand::parse("As Kd Qh Jc Ts").unwrap();
+    assert_eq!("AsKdQhJcTs".parse::<Hand>().unwrap(), spaced);
+    assert_eq!(Hand::try_from("A♠K♦Q♥J♣10♠").unwrap(), spaced);
+    assert_eq!(Hand::parse("AsKd QhJc 10s").unwrap(), spaced)
+}
+
+#[test]
+fn test_invalid_notation_is_rejected() {
+    assert_eq!(
+        "1X".parse::<Card>(),
+        Err(CardError::IncorrectCard { card: "1".into() })
+    );
+    assert_eq!(
+        "AZ".parse::<Card>(),
+        Err(CardError::IncorrectSuit { suit: 'Z' })
+    );
+    for card in ["1", "A", "♠", "10", "Ten"].iter() {
+        assert_eq!(
+            card.parse::<Card>(),
+            Err(CardError::IncorrectCard {
+                card: (*card).into()
+            })
+        );
+    }
+    assert_eq!("AsKdQhJcT".parse::<Hand>().unwrap_err().position, 4)
+}
+
+#[test]
+fn test_card_display_formats() {
+    let card = Card::new(Rank::Ace, Suit::Hearts);
+    assert_eq!(card.to_string(), "Ah");
+    assert_eq!(format!("{:#}", card), "Ace of Hearts");
+    assert_eq!(card.display(CardFormat::Short).to_string(), "Ah");
+    assert_eq!(card.display(CardFormat::Long).to_string(), "Ace of Hearts");
+    assert_eq!(card.display(CardFormat::Unicode).to_string(), "A♥");
+    assert_eq!(Card::new(Rank::Ten, Suit::Clubs).to_string(), "Tc")
+}
+
+#[test]
+fn test_hand_display_formats() {
+    let hand = Hand::parse("10D JH QS KD AC").unwrap();
+    assert_eq!(hand.to_string(), "Ac Kd Qs Jh Td");
+    assert_eq!(
+        format!("{:#}", hand),
+        "Ace of Clubs, King of Diamonds, Queen of Spades, Jack of Hearts, Ten of Diamonds"
+    );
+    assert_eq!(
+        hand.display(CardFormat::Unicode).to_string(),
+        "A♣ K♦ Q♠ J♥ T♦"
+    )
+}
+
+#[test]
+fn test_every_card_round_trips_in_every_format() {
+    let ranks = [
+        "2", "3", "4", "5", "6", "7", "8", "9", "T", "J", "Q", "K", "A",
+    ]
+    .iter()
+    .map(|rank| rank.parse::<Rank>().unwrap());
+    for rank in ranks {
+        for suit in [Suit::Hearts, Suit::Diamonds, Suit::Spades, Suit::Clubs].iter() {
+            let card = Card::new(rank, *suit);
+            for format in [CardFormat::Short, CardFormat::Long, CardFormat::Unicode].iter() {
+                assert_eq!(card.display(*format).to_string().parse::<Card>(), Ok(card));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_hand_round_trips() {
+    let hand = Hand::parse("4D AH 3S 2D 5C").unwrap();
+    assert_eq!(hand.to_string().parse::<Hand>().unwrap(), hand);
+    assert_eq!(
+        hand.display(CardFormat::Unicode)
+            .to_string()
+            .parse::<Hand>()
+            .unwrap(),
+        hand
+    )
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "std")]
+
+use poker::{
+    evaluate_low, omaha_best_hand, omaha_best_low, omaha_hi_lo_winners, omaha_winners, CardError,
+    HiLoWinners, Rank, Ranking,
+};
+
+mod common;
+use common::cards;
+
+#[test]
+fn test_four_suited_board_cards_need_two_suited_hole_cards() {
+    // Four hearts on board and one in hand is no flush in Omaha.
+    let board = cards("2H 5H 9H KH 3C");
+    let (rank, _) = omaha_best_hand(&cards("AH AS 7D 8C"), &board);
+    assert_eq!(rank.ranking(), Ranking::OnePair);
+
+    let (rank, hand) = omaha_best_hand(&cards("AH QH 7D 8C"), &board);
+    assert_eq!(rank.ranking(), Ranking::Flush);
+    assert_eq!(hand.to_vec(), cards("AH KH QH 9H 5H"));
+}
+
+#[test]
+fn test_board_straight_does_not_play() {
+    let board = cards("5C 6D 7H 8S 9C");
+    let (rank, _) = omaha_best_hand(&cards("AS AD KH KC"), &board);
+    assert_eq!(rank.ranking(), Ranking::OnePair);
+}
+
+#[test]
+fn test_only_two_hole_cards_play() {
+    // Three aces in hand still make only trips with the single ace needed from the board.
+    let board = cards("AC 7D 2H 9S JC");
+    let (rank, _) = omaha_best_hand(&cards("AS AD AH KC"), &board);
+    assert_eq!(rank.ranking(), Ranking::ThreeOfAKind);
+}
+
+#[test]
+fn test_five_and_six_card_variants() {
+    let board = cards("TH JH 2C 2D 7S");
+    let (rank, _) = omaha_best_hand(&cards("QH KH 3S 4D 7C"), &board);
+    assert_eq!(rank.ranking(), Ranking::TwoPair);
+    let (rank, _) = omaha_best_hand(&cards("QH KH 3S 4D 7C 2S"), &board);
+    assert_eq!(rank.ranking(), Ranking::FullHouse);
+}
+
+#[test]
+#[should_panic]
+fn test_three_hole_cards_are_rejected() {
+    omaha_best_hand(&cards("AS KS QS"), &cards("2H 3H 4H 5H 6H"));
+}
+
+#[test]
+fn test_low_ordering() {
+    let seven = evaluate_low(&cards("7S 5D 4H 3C 2S")).unwrap();
+    let eight = evaluate_low(&cards("8S 4D 3H 2C AS")).unwrap();
+    let wheel = evaluate_low(&cards("5S 4D 3H 2C AS")).unwrap();
+    assert!(wheel > seven);
+    assert!(seven > eight);
+    assert_eq!(
+        wheel.ranks().collect::<Vec<_>>(),
+        vec![Rank::Five, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]
+    );
+}
+
+#[test]
+fn test_low_does_not_qualify() {
+    assert_eq!(evaluate_low(&cards("9S 5D 4H 3C 2S")), None);
+    assert_eq!(evaluate_low(&cards("5S 5D 4H 3C 2S")), None);
+}
+
+#[test]
+fn test_best_low_uses_two_hole_cards() {
+    let board = cards("2C 5D 8H KS QC");
+    let (rank, hand) = omaha_best_low(&cards("AS 3D 4H 4C"), &board).unwrap();
+    // 4-3 would make a better low, but only two hole cards may play.
+    assert_eq!(rank, evaluate_low(&cards("8H 5D 3D 2C AS")).unwrap());
+    assert_eq!(hand.to_vec(), cards("8H 5D 3D 2C AS"));
+    // Only two low cards on board, so no low is possible.
+    assert_eq!(
+        omaha_best_low(&cards("AS 3D 4H 6C"), &cards("2C 9D TH KS QC")),
+        None
+    );
+}
+
+#[test]
+fn test_winners_high_only() {
+    let board = cards("2H 5H 9H KH 3C");
+    let hands = [&cards("AH AS 7D 8C")[..], &cards("QH 4H 7S 8S")[..]];
+    assert_eq!(omaha_winners(&board, &hands), Ok(vec![1]));
+}
+
+#[test]
+fn test_hi_lo_scoop() {
+    let board = cards("2C 3D 7H KS KC");
+    let hands = [&cards("AS 4D KH QD")[..], &cards("QS JD 9H 9C")[..]];
+    let winners = omaha_hi_lo_winners(&board, &hands).unwrap();
+    assert_eq!(
+        winners,
+        HiLoWinners {
+            high: vec![0],
+            low: vec![0]
+        }
+    );
+    assert_eq!(winners.share(0), 1.0);
+    assert_eq!(winners.share(1), 0.0);
+}
+
+#[test]
+fn test_hi_lo_without_low_gives_whole_pot_to_high() {
+    let board = cards("9C TD JH KS KC");
+    let hands = [&cards("AS 2D QH QD")[..], &cards("QS 3D 9H 9D")[..]];
+    let winners = omaha_hi_lo_winners(&board, &hands).unwrap();
+    assert!(winners.low.is_empty());
+    assert_eq!(winners.share(winners.high[0]), 1.0);
+}
+
+#[test]
+fn test_hi_lo_quartering() {
+    // Player 0 has the nut high (a flush) and ties the low with player 1.
+    let board = cards("2H 3H 7H KS QC");
+    let hands = [
+        &cards("AH 4H 9S 9D")[..],
+        &cards("AC 4D JS JD")[..],
+        &cards("KD KH 8S 8D")[..],
+    ];
+    let winners = omaha_hi_lo_winners(&board, &hands).unwrap();
+    assert_eq!(winners.high, vec![0]);
+    assert_eq!(winners.low, vec![0, 1]);
+    assert_eq!(winners.share(0), 0.75);
+    assert_eq!(winners.share(1), 0.25);
+    assert_eq!(winners.share(2), 0.0);
+}
+
+#[test]
+fn test_deal_errors() {
+    let board = cards("2H 3H 7H KS QC");
+    let error = omaha_winners(&board, &[&cards("AH 4H 9S")[..]]).unwrap_err();
+    assert_eq!(error.hand, 0);
+    assert_eq!(
+        error.source,
+        CardError::WrongHandSize {
+            expected: 4,
+            found: 3
+        }
+    );
+
+    let error = omaha_winners(&board, &[&cards("AH 4H 9S 2H")[..]]).unwrap_err();
+    assert_eq!(error.hand, 1);
+    assert!(matches!(
+        error.source,
+        CardError::CardSharedBetweenHands { hand: 0, .. }
+    ));
+}
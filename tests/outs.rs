@@ -0,0 +1,85 @@
+#![cfg(feature = "std")]
+
+use poker::{outs, Card, CardError, Ranking};
+
+mod common;
+use common::{cards, hole, range};
+
+#[test]
+fn test_flush_draw_against_a_set() {
+    let outs = outs(hole("AH KH"), &cards("2H 7H QC"), &range("QsQd")).unwrap();
+    assert_eq!(outs.len(), 9);
+    assert!(outs.cards().iter().all(|out| out.ranking == Ranking::Flush));
+    //The queen of hearts gives the set quads.
+    let tainted: Vec<Card> = outs
+        .cards()
+        .iter()
+        .filter(|out| out.tainted)
+        .map(|out| out.card)
+        .collect();
+    assert_eq!(tainted, cards("QH"));
+    assert_eq!(outs.to_string(), "9 outs to Flush (1 tainted)");
+    //45 unseen cards, two to come.
+    let expected = 1.0 - (36.0 / 45.0) * (35.0 / 44.0);
+    assert!((outs.probability() - expected).abs() < 1e-12);
+    let clean = 1.0 - (37.0 / 45.0) * (36.0 / 44.0);
+    assert!((outs.clean_probability() - clean).abs() < 1e-12);
+}
+
+#[test]
+fn test_outs_grouped_by_category() {
+    let outs = outs(hole("9H 8H"), &cards("TH JC 2H"), &range("AsAd")).unwrap();
+    let groups: Vec<(Ranking, usize)> = outs
+        .by_ranking()
+        .iter()
+        .map(|(ranking, group)| (*ranking, group.len()))
+        .collect();
+    assert_eq!(groups, vec![(Ranking::Flush, 9), (Ranking::Straight, 6)]);
+    //The ace and the jack of hearts improve the aces too.
+    assert_eq!(outs.to_string(), "9 outs to Flush (2 tainted), 6 to Straight");
+}
+
+#[test]
+fn test_one_card_to_come_on_the_turn() {
+    let outs = outs(hole("AH KH"), &cards("2H 7H QC 3C"), &range("QsQd")).unwrap();
+    assert_eq!(outs.len(), 9);
+    assert!((outs.probability() - 9.0 / 44.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_against_a_range() {
+    let outs = outs(hole("AH KH"), &cards("2H 7H QC"), &range("AA, 77")).unwrap();
+    assert_eq!(outs.to_string(), "9 outs to Flush (1 tainted)");
+    //Nothing is known of the opponent's cards, so 47 are unseen.
+    let expected = 1.0 - (38.0 / 47.0) * (37.0 / 46.0);
+    assert!((outs.probability() - expected).abs() < 1e-12);
+}
+
+#[test]
+fn test_no_outs() {
+    let outs = outs(hole("2C 3D"), &cards("AH AS AD"), &range("AcKd")).unwrap();
+    assert!(outs.is_empty());
+    assert_eq!(outs.to_string(), "no outs");
+    assert_eq!(outs.probability(), 0.0);
+}
+
+#[test]
+fn test_invalid_deals() {
+    let error = outs(hole("AH KH"), &cards("2H 7H"), &range("QQ")).unwrap_err();
+    assert_eq!(error.hand, 1);
+    assert!(matches!(error.source, CardError::WrongHandSize { .. }));
+
+    let error = outs(hole("AH KH"), &cards("2H 7H QC 3C 4C"), &range("QQ")).unwrap_err();
+    assert_eq!(error.hand, 1);
+
+    let error = outs(hole("AH KH"), &cards("2H KH QC"), &range("QQ")).unwrap_err();
+    assert_eq!(error.hand, 1);
+    assert!(matches!(
+        error.source,
+        CardError::CardSharedBetweenHands { hand: 0, .. }
+    ));
+
+    let error = outs(hole("AH KH"), &cards("2H 7H QC"), &range("AhAs")).unwrap_err();
+    assert_eq!(error.hand, 2);
+    assert_eq!(error.source, CardError::NoCombination);
+}
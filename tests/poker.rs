@@ -1,4 +1,8 @@
-use poker::winning_hands;
+#![cfg(feature = "std")]
+
+use poker::{
+    try_winning_hands, winning_hands, Card, CardError, Hand, HandRank, PokerError, Rank, Suit,
+};
 use std::collections::HashSet;
 
 fn hs_from<'a>(input: &[&'a str]) -> HashSet<&'a str> {
@@ -14,13 +18,37 @@ fn hs_from<'a>(input: &[&'a str]) -> HashSet<&'a str> {
 ///
 /// Note that the output can be in any order. Here, we use a HashSet to
 /// abstract away the order of outputs.
-fn test<'a, 'b>(input: &[&'a str], expected: &[&'b str]) {
+fn test(input: &[&str], expected: &[&str]) {
     assert_eq!(
         hs_from(&winning_hands(input).expect("This test should produce Some value",)),
         hs_from(expected)
     )
 }
 
+/// Hands which could only be dealt from more than one deck are rejected by `winning_hands`.
+/// The tie-break they exercise is still checked by ranking each hand on its own.
+fn test_multiple_decks(input: &[&str], expected: &[&str]) {
+    assert!(matches!(
+        try_winning_hands(input),
+        Err(PokerError {
+            source: CardError::CardSharedBetweenHands { .. },
+            ..
+        })
+    ));
+    let ranks: Vec<HandRank> = input
+        .iter()
+        .map(|hand| Hand::parse(hand).expect("Each hand should parse").rank())
+        .collect();
+    let best = ranks.iter().max();
+    let winners: Vec<&str> = input
+        .iter()
+        .zip(&ranks)
+        .filter(|&(_, rank)| Some(rank) == best)
+        .map(|(&hand, _)| hand)
+        .collect();
+    assert_eq!(hs_from(&winners), hs_from(expected))
+}
+
 #[test]
 fn test_single_hand_always_wins() {
     test(&["4S 5S 7H 8D JC"], &["4S 5S 7H 8D JC"])
@@ -95,7 +123,7 @@ fn test_two_pairs_second_pair_cascade() {
 fn test_two_pairs_last_card_cascade() {
     // both hands have two identically ranked pairs,
     // tie goes to remaining card (kicker)
-    test(&["JD QH JS 8D QC", "JS QS JC 2D QD"], &["JD QH JS 8D QC"])
+    test(&["JD QH JS 8D QC", "JH QS JC 2D QD"], &["JD QH JS 8D QC"])
 }
 
 #[test]
@@ -116,7 +144,7 @@ fn test_three_of_a_kind_ranks() {
 fn test_three_of_a_kind_cascade_ranks() {
     // with multiple decks, two players can have same three of a kind,
     // ties go to highest remaining cards
-    test(&["4S AH AS 7C AD", "4S AH AS 8C AD"], &["4S AH AS 8C AD"])
+    test_multiple_decks(&["4S AH AS 7C AD", "4S AH AS 8C AD"], &["4S AH AS 8C AD"])
 }
 
 #[test]
@@ -183,7 +211,7 @@ fn test_full_house_ranks() {
 #[ignore]
 fn test_full_house_cascade() {
     // with multiple decks, both hands have a full house with the same triplet, tie goes to the pair
-    test(&["5H 5S 5D 9S 9D", "5H 5S 5D 8S 8D"], &["5H 5S 5D 9S 9D"])
+    test_multiple_decks(&["5H 5S 5D 9S 9D", "5H 5S 5D 8S 8D"], &["5H 5S 5D 9S 9D"])
 }
 
 #[test]
@@ -203,7 +231,7 @@ fn test_four_of_a_kind_ranks() {
 #[ignore]
 fn test_four_of_a_kind_cascade() {
     // with multiple decks, both hands with identical four of a kind, tie determined by kicker
-    test(&["3S 3H 2S 3D 3C", "3S 3H 4S 3D 3C"], &["3S 3H 4S 3D 3C"])
+    test_multiple_decks(&["3S 3H 2S 3D 3C", "3S 3H 4S 3D 3C"], &["3S 3H 4S 3D 3C"])
 }
 
 #[test]
@@ -218,3 +246,117 @@ fn test_straight_flush_ranks() {
     // both hands have straight flush, tie goes to highest-ranked card
     test(&["4H 6H 7H 8H 5H", "5S 7S 8S 9S 6S"], &["5S 7S 8S 9S 6S"])
 }
+
+#[test]
+fn test_invalid_card_is_reported_with_its_position() {
+    assert_eq!(
+        try_winning_hands(&["4S 5S 7H 8D JC", "2S 4C 1X 9H 10H"]),
+        Err(PokerError {
+            hand: 1,
+            position: 2,
+            source: CardError::IncorrectCard { card: "1".into() },
+        })
+    )
+}
+
+#[test]
+fn test_invalid_suit_is_reported_with_its_position() {
+    assert_eq!(
+        try_winning_hands(&["4S 5S 7H 8D JZ"]),
+        Err(PokerError {
+            hand: 0,
+            position: 4,
+            source: CardError::IncorrectSuit { suit: 'Z' },
+        })
+    )
+}
+
+#[test]
+fn test_invalid_hand_does_not_panic() {
+    assert_eq!(winning_hands(&["4S 5S 7H 8D JC", "S ♠ 10"]), None)
+}
+
+#[test]
+fn test_six_high_straight_flush_beats_wheel_straight_flush() {
+    test(&["AD 2D 3D 4D 5D", "2H 3H 4H 5H 6H"], &["2H 3H 4H 5H 6H"])
+}
+
+#[test]
+fn test_wheel_straight_flush_beats_four_kings() {
+    test(&["KS KH KD KC AS", "5C 4C 3C 2C AC"], &["5C 4C 3C 2C AC"])
+}
+
+#[test]
+fn test_wheels_tie() {
+    test(
+        &["AS 2H 3D 4C 5S", "5H 4D 3C 2S AD"],
+        &["AS 2H 3D 4C 5S", "5H 4D 3C 2S AD"],
+    )
+}
+
+#[test]
+fn test_ace_and_two_without_a_straight_is_not_a_straight() {
+    // A-A-2-3-4 is a pair of aces, not a wheel, and loses to a real wheel
+    test(&["AS AH 2D 3C 4S", "AC 2H 3H 4H 5D"], &["AC 2H 3H 4H 5D"])
+}
+
+#[test]
+fn test_too_few_cards_are_rejected() {
+    assert_eq!(
+        try_winning_hands(&["4S 5S 7H 8D JC", "AS KS"]),
+        Err(PokerError {
+            hand: 1,
+            position: 2,
+            source: CardError::WrongHandSize {
+                expected: 5,
+                found: 2,
+            },
+        })
+    )
+}
+
+#[test]
+fn test_too_many_cards_are_rejected() {
+    assert_eq!(
+        try_winning_hands(&["4S 5S 7H 8D JC 2H 3H"]),
+        Err(PokerError {
+            hand: 0,
+            position: 5,
+            source: CardError::WrongHandSize {
+                expected: 5,
+                found: 7,
+            },
+        })
+    )
+}
+
+#[test]
+fn test_duplicate_card_within_a_hand_is_rejected() {
+    assert_eq!(
+        try_winning_hands(&["4S 5S 7H 8D JC", "2S 4C 9H 4C 10H"]),
+        Err(PokerError {
+            hand: 1,
+            position: 3,
+            source: CardError::DuplicateCard {
+                card: Card::new(Rank::Four, Suit::Clubs),
+                first: 1,
+            },
+        })
+    )
+}
+
+#[test]
+fn test_card_shared_between_hands_is_rejected() {
+    assert_eq!(
+        try_winning_hands(&["4S 5S 7H 8D JC", "2S 4C 9H JC 10H"]),
+        Err(PokerError {
+            hand: 1,
+            position: 3,
+            source: CardError::CardSharedBetweenHands {
+                card: Card::new(Rank::Jack, Suit::Clubs),
+                hand: 0,
+                position: 4,
+            },
+        })
+    )
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "std")]
+
+use poker::{Card, CardError, Range};
+
+mod common;
+use common::{hole, range};
+
+#[test]
+fn test_combo_counts() {
+    assert_eq!(range("AA").len(), 6);
+    assert_eq!(range("AKs").len(), 4);
+    assert_eq!(range("AKo").len(), 12);
+    assert_eq!(range("AK").len(), 16);
+    assert_eq!(range("AhKh").len(), 1);
+    assert_eq!(range("").len(), 0);
+    assert!(range("").is_empty());
+}
+
+#[test]
+fn test_plus_notation() {
+    assert_eq!(range("QQ+"), range("QQ, KK, AA"));
+    assert_eq!(range("22+").len(), 78);
+    assert_eq!(range("A9s+"), range("A9s, ATs, AJs, AQs, AKs"));
+    assert_eq!(range("K9o+"), range("K9o, KTo, KJo, KQo"));
+    // Connectors move both cards up.
+    assert_eq!(
+        range("76s+"),
+        range("76s, 87s, 98s, T9s, JTs, QJs, KQs, AKs")
+    );
+}
+
+#[test]
+fn test_dash_spans() {
+    assert_eq!(range("TT-77"), range("TT, 99, 88, 77"));
+    assert_eq!(range("77-TT"), range("TT-77"));
+    assert_eq!(range("A5s-A2s"), range("A5s, A4s, A3s, A2s"));
+    assert_eq!(range("KQo-T9o"), range("KQo, QJo, JTo, T9o"));
+}
+
+#[test]
+fn test_weights() {
+    let weighted = range("AKs:0.5, QQ+");
+    assert_eq!(weighted.weight(hole("AH KH")), 0.5);
+    assert_eq!(weighted.weight(hole("AH KD")), 0.0);
+    assert_eq!(weighted.weight(hole("QS QD")), 1.0);
+    // Later items override earlier ones.
+    assert_eq!(range("AKs, AhKh:0.25").weight(hole("KH AH")), 0.25);
+    let total: f64 = weighted.combos().map(|(_, weight)| weight).sum();
+    assert_eq!(total, 2.0 + 18.0);
+}
+
+#[test]
+fn test_case_and_spacing() {
+    assert_eq!(range("qq+,aks"), range("QQ+, AKs"));
+    assert_eq!(range(" AKs , 22 "), range("AKs, 22"));
+}
+
+#[test]
+fn test_invalid_items() {
+    for bad in [
+        "AKx", "AAs", "A", "QQ++", "AKs:2", "AKs:x", "A5s-K2s", "AhAh", "TT-A9s",
+    ]
+    .iter()
+    {
+        assert!(
+            matches!(bad.parse::<Range>(), Err(CardError::IncorrectRange { .. })),
+            "{}",
+            bad
+        );
+    }
+    assert_eq!(
+        "QQ+, AK, 1x".parse::<Range>(),
+        Err(CardError::IncorrectRange { item: "1x".into() })
+    );
+}
+
+#[test]
+fn test_without_dead_cards() {
+    let dead: Vec<Card> = vec!["AH".parse().unwrap(), "KD".parse().unwrap()];
+    let live = range("AA, AKs").without(&dead);
+    // AdKd goes with the king of diamonds.
+    assert_eq!(live.len(), 3 + 2);
+    assert!(live
+        .combos()
+        .all(|(hole, _)| !hole.contains(&dead[0]) && !hole.contains(&dead[1])));
+}
+
+#[test]
+fn test_combos_put_the_higher_card_first() {
+    for (hole, _) in range("AK, 72o, 55").combos() {
+        assert!(hole[0].rank() >= hole[1].rank());
+    }
+    assert_eq!(range("AKs, QQ").combos().next().unwrap().0, hole("AH KH"));
+}
+
+#[test]
+fn test_canonical_display() {
+    let cases = [
+        ("AA, KK, QQ", "QQ+"),
+        ("TT, 99, 88, 77", "TT-77"),
+        ("55", "55"),
+        ("AKs, AKo", "AK"),
+        ("A5s, A4s, A3s, A2s", "A5s-A2s"),
+        ("AKs, AQs, AJs", "AJs+"),
+        ("AKs", "AKs"),
+        ("AKs:0.5, QQ+", "QQ+, AKs:0.5"),
+        ("AhKh", "AhKh"),
+        ("76s+", "76s+"),
+        ("KQo-T9o, KQs-T9s", "KQ-T9"),
+        ("KQo-T9o", "KQo-T9o"),
+        ("AQs-J9s, 64s", "AQs-J9s, 64s"),
+        ("AKs, AQs, KQs", "AQs+, KQs"),
+        ("22+, A2+", "22+, A2+"),
+        ("KQo, KJo, K9s-K7s, AKs:0.25", "AKs:0.25, K9s-K7s, KJo+"),
+    ];
+    for (input, expected) in cases.iter() {
+        assert_eq!(range(input).to_string(), *expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_display_round_trips() {
+    for input in [
+        "QQ+, AKs:0.5, A5s-A2s, 76s+, AhKh, KTo:0.75",
+        "22+, A2s+, K9o+, T8s-64s",
+        "KQ-T9, K8o:0.5, J9s-75s, AJo+",
+        "AA, AsKs, AhKd:0.3",
+        "",
+    ]
+    .iter()
+    {
+        let parsed = range(input);
+        assert_eq!(range(&parsed.to_string()), parsed, "{}", parsed);
+    }
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "std")]
+
+use poker::{exact_equity, range_equity, Card, CardError, HoleCards, Range};
+
+mod common;
+use common::{cards, hole, range};
+
+#[test]
+fn test_single_combos_match_exact_equity() {
+    let board = cards("2H 7H QC");
+    let ranges = [range("AhKh"), range("QsQd")];
+    let results = range_equity(&ranges, &board, 10_000, 1).unwrap();
+    let exact = exact_equity(&[hole("AH KH"), hole("QS QD")], &board, &[]).unwrap();
+    for (result, exact) in results.iter().zip(&exact) {
+        assert!(result.exhaustive);
+        assert!((result.equity - exact.equity()).abs() < 1e-9);
+        assert_eq!(result.combos.len(), 1);
+        assert_eq!(result.combos[0].frequency, 1.0);
+    }
+}
+
+#[test]
+fn test_aces_against_kings_by_sampling() {
+    let results = range_equity(&[range("AA"), range("KK")], &[], 5_000, 3).unwrap();
+    assert!(!results[0].exhaustive);
+    assert!((results[0].equity - 0.82).abs() < 0.03, "{:?}", results[0].equity);
+    assert_eq!(results[0].combos.len(), 6);
+}
+
+#[test]
+fn test_equities_add_up_to_one() {
+    let ranges = [range("QQ+, AKs"), range("T9s, 98s"), range("22-55")];
+    let board = cards("9H 8H 4C 2D KS");
+    for iterations in [1_000, 1_000_000] {
+        let results = range_equity(&ranges, &board, iterations, 5).unwrap();
+        let total: f64 = results.iter().map(|result| result.equity).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        for result in &results {
+            let frequency: f64 = result.combos.iter().map(|combo| combo.frequency).sum();
+            assert!((frequency - 1.0).abs() < 1e-9);
+            let weighted: f64 = result
+                .combos
+                .iter()
+                .map(|combo| combo.frequency * combo.equity)
+                .sum();
+            assert!((weighted - result.equity).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_blockers_are_respected() {
+    //Only the combination without the ace of hearts or spades can be dealt against AhAs.
+    let board = cards("2C 3C 4D 8S 9S");
+    let results = range_equity(&[range("AhAs"), range("AA")], &board, 100, 0).unwrap();
+    let dealt: Vec<HoleCards> = results[1]
+        .combos
+        .iter()
+        .filter(|combo| combo.frequency > 0.0)
+        .map(|combo| combo.hole)
+        .collect();
+    assert_eq!(dealt, vec![hole("AD AC")]);
+    assert!((results[0].equity - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_weights_shape_frequencies() {
+    let board = cards("2C 3C 4D 8S 9S");
+    let results = range_equity(&[range("AKs, AKo:0.5"), range("22")], &board, 1_000_000, 0)
+        .unwrap();
+    let suited: f64 = results[0]
+        .combos
+        .iter()
+        .filter(|combo| combo.hole[0].suit() == combo.hole[1].suit())
+        .map(|combo| combo.frequency)
+        .sum();
+    //Four suited combos at full weight against twelve offsuit at half weight.
+    assert!((suited - 0.4).abs() < 1e-9, "{}", suited);
+}
+
+#[test]
+fn test_same_seed_same_result() {
+    let ranges = [range("TT+, AQs+"), range("76s, 65s, 54s")];
+    let first = range_equity(&ranges, &[], 500, 11).unwrap();
+    assert_eq!(first, range_equity(&ranges, &[], 500, 11).unwrap());
+}
+
+#[test]
+fn test_range_which_cannot_be_dealt() {
+    let error = range_equity(&[range("AhAs"), range("AsAd")], &[], 100, 0).unwrap_err();
+    assert_eq!(error.hand, 1);
+    assert_eq!(error.source, CardError::NoCombination);
+
+    let error = range_equity(&[range("KK"), range("")], &[], 100, 0).unwrap_err();
+    assert_eq!(error.hand, 1);
+
+    let board = cards("AH AS 2C");
+    let error = range_equity(&[range("AhAs")], &board, 100, 0).unwrap_err();
+    assert_eq!(error.hand, 0);
+    assert_eq!(error.source, CardError::NoCombination);
+}
+
+#[test]
+fn test_invalid_board() {
+    let error = range_equity(&[range("AA")], &cards("2C 2C"), 100, 0).unwrap_err();
+    assert_eq!(error.hand, 1);
+    assert!(matches!(error.source, CardError::DuplicateCard { .. }));
+}
+
+#[test]
+fn test_too_many_ranges_for_the_board() {
+    let deck: Vec<Card> = Card::deck().collect();
+    let ranges: Vec<Range> = deck[..48]
+        .chunks(2)
+        .map(|hole| range(&format!("{}{}", hole[0], hole[1])))
+        .collect();
+    let error = range_equity(&ranges, &[], 100, 0).unwrap_err();
+    assert_eq!(error.hand, 24);
+    assert_eq!(
+        error.source,
+        CardError::NotEnoughCards {
+            requested: 5,
+            remaining: 4
+        }
+    );
+}
+
+#[test]
+fn test_no_iterations_gives_zero_rather_than_nan() {
+    let results = range_equity(&[range("AA"), range("KK")], &[], 0, 0).unwrap();
+    for result in &results {
+        assert_eq!(result.equity, 0.0);
+        assert!(result
+            .combos
+            .iter()
+            .all(|combo| combo.frequency == 0.0 && combo.equity == 0.0));
+    }
+}
@@ -0,0 +1,235 @@
+#![cfg(feature = "std")]
+
+use poker::{
+    holdem_winners_with, try_winning_hands_with, winning_hands_with, Hand, HandRank, Rank, Ranking,
+    RankingRules,
+};
+
+mod common;
+use common::{cards, hole};
+
+fn rank(rules: RankingRules, hand: &str) -> HandRank {
+    rules.evaluate(&cards(hand))
+}
+
+/// Checks that every hand in the table strictly beats the one before it under `rules`, and that
+/// the hands listed together on one line tie.
+fn assert_ascending(rules: RankingRules, table: &[&[&str]]) {
+    let ranks: Vec<Vec<HandRank>> = table
+        .iter()
+        .map(|tied| tied.iter().map(|hand| rank(rules, hand)).collect())
+        .collect();
+    for (tied, line) in ranks.iter().zip(table) {
+        assert!(tied.iter().all(|&r| r == tied[0]), "{:?} should tie", line);
+    }
+    for i in 0..ranks.len() {
+        for j in i + 1..ranks.len() {
+            assert!(
+                ranks[i][0] < ranks[j][0],
+                "{:?} should lose to {:?} under {:?}",
+                table[i],
+                table[j],
+                rules
+            );
+        }
+    }
+}
+
+/// Ace-to-five, worst to best.
+const ACE_TO_FIVE: &[&[&str]] = &[
+    &["KS KH KD KC QS"],
+    &["KS KH KD QC QS"],
+    &["2S 2H 2D 3C 4S"],
+    &["KS KH QD QC 2S"],
+    &["3S 3H 2D 2C 4S"],
+    &["KS KH 2D 3C 4S"],
+    &["2S 2H KD QC JS"],
+    &["2S 2H 5D 4C 3S"],
+    &["AS AH 5D 4C 3S"],
+    &["KS QH JD TC 9S"],
+    &["KS 4H 3D 2C AS"],
+    &["8S 7H 6D 5C 4S"],
+    &["8S 7H 6D 5C 3S"],
+    &["8S 4H 3D 2C AS"],
+    &["7S 6H 4D 3C 2S"],
+    &["6S 5H 4D 3C 2S", "6H 5H 4H 3H 2H"],
+    &["6S 4H 3D 2C AS"],
+    &["5S 4H 3D 2C AS", "5H 4H 3H 2H AH"],
+];
+
+/// Deuce-to-seven, worst to best.
+const DEUCE_TO_SEVEN: &[&[&str]] = &[
+    &["AS KS QS JS TS"],
+    &["6H 5H 4H 3H 2H"],
+    &["2S 2H 2D 2C 3S"],
+    &["2S 2H 2D 3C 3S"],
+    &["7H 5H 4H 3H 2H"],
+    &["AS KH QD JC TS"],
+    &["6S 5H 4D 3C 2S"],
+    &["2S 2H 2D 3C 4S"],
+    &["3S 3H 2D 2C 4S"],
+    &["AS AH 2D 3C 4S"],
+    &["2S 2H 3D 4C 5S"],
+    &["AS KH 2D 3C 4S"],
+    &["AS 2H 3D 4C 5S"],
+    &["KS QH JD TC 8S"],
+    &["8S 6H 5D 4C 3S"],
+    &["8S 5H 4D 3C 2S"],
+    &["7S 6H 5D 4C 2S"],
+    &["7S 5H 4D 3C 2S", "7D 5C 4H 3S 2H"],
+];
+
+#[test]
+fn test_high_rules_match_evaluate() {
+    for hand in ["AS KS QS JS TS", "5S 4H 3D 2C AS", "2S 2H 5D 4C 3S"].iter() {
+        assert_eq!(
+            rank(RankingRules::High, hand),
+            poker::evaluate(&cards(hand))
+        );
+    }
+    assert_eq!(RankingRules::default(), RankingRules::High);
+}
+
+#[test]
+fn test_ace_to_five_table() {
+    assert_ascending(RankingRules::AceToFive, ACE_TO_FIVE);
+}
+
+#[test]
+fn test_deuce_to_seven_table() {
+    assert_ascending(RankingRules::DeuceToSeven, DEUCE_TO_SEVEN);
+}
+
+#[test]
+fn test_razz_ranks_five_cards_like_ace_to_five() {
+    for tied in ACE_TO_FIVE {
+        for hand in tied.iter() {
+            assert_eq!(
+                rank(RankingRules::Razz, hand),
+                rank(RankingRules::AceToFive, hand)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_razz_plays_the_best_five_of_seven() {
+    // Razz, worst to best, as seven card hands.
+    let table: &[&str] = &[
+        "KS KH KD 2C 2S 3D 3C",
+        "KS QH JD TC 9S 9D 9H",
+        "KS KH QD 2C 2S 3D 4C",
+        "8S 7H 6D 5C 4S KD KC",
+        "7S 6H 4D 3C 2S 2D 7C",
+        "5S 4H 3D 2C AS KD KC",
+    ];
+    let ranks: Vec<HandRank> = table
+        .iter()
+        .map(|hand| RankingRules::Razz.best_hand(&cards(hand)).0)
+        .collect();
+    for pair in ranks.windows(2) {
+        assert!(pair[0] < pair[1]);
+    }
+
+    let (rank, hand) = RankingRules::Razz.best_hand(&cards("KS KH KD 2C 2S 3D 3C"));
+    assert_eq!(rank.ranking(), Ranking::TwoPair);
+    assert_eq!(
+        rank.tie_break().collect::<Vec<_>>(),
+        vec![Rank::Three, Rank::Two, Rank::King]
+    );
+    assert_eq!(hand.len(), 5);
+}
+
+#[test]
+fn test_lowball_categories_and_tie_break() {
+    let wheel = rank(RankingRules::AceToFive, "5H 4H 3H 2H AH");
+    assert_eq!(wheel.ranking(), Ranking::HighCard);
+    assert_eq!(
+        wheel.tie_break().collect::<Vec<_>>(),
+        vec![Rank::Five, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]
+    );
+
+    let wheel = rank(RankingRules::DeuceToSeven, "AS 2H 3D 4C 5S");
+    assert_eq!(wheel.ranking(), Ranking::HighCard);
+    assert_eq!(wheel.tie_break().next(), Some(Rank::Ace));
+    let flush = rank(RankingRules::DeuceToSeven, "7H 5H 4H 3H 2H");
+    assert_eq!(flush.ranking(), Ranking::Flush);
+}
+
+#[test]
+fn test_eight_or_better_qualifier() {
+    let rules = RankingRules::EightOrBetter;
+    // Qualifying, worst to best.
+    let qualifying = [
+        "8S 7H 6D 5C 4S",
+        "8S 4H 3D 2C AS",
+        "7S 5H 4D 3C 2S",
+        "5S 4H 3D 2C AS",
+    ];
+    for hand in qualifying.iter() {
+        assert!(rules.qualifies(rank(rules, hand)), "{}", hand);
+    }
+    for pair in qualifying.windows(2) {
+        assert!(rank(rules, pair[0]) < rank(rules, pair[1]));
+    }
+    for hand in ["9S 5H 4D 3C 2S", "8S 8H 4D 3C 2S", "KS 4H 3D 2C AS"].iter() {
+        assert!(!rules.qualifies(rank(rules, hand)), "{}", hand);
+    }
+    // Only the eight-or-better rules have a qualifier.
+    assert!(RankingRules::AceToFive.qualifies(rank(RankingRules::AceToFive, "KS KH KD KC QS")));
+}
+
+#[test]
+fn test_winning_hands_with_lowball() {
+    let hands = ["7S 5H 4D 3C 2S", "AH 2D 3S 4H 5C", "KD KH KC 9S 9D"];
+    assert_eq!(
+        winning_hands_with(&hands, RankingRules::DeuceToSeven),
+        Some(vec!["7S 5H 4D 3C 2S"])
+    );
+    assert_eq!(
+        winning_hands_with(&hands, RankingRules::AceToFive),
+        Some(vec!["AH 2D 3S 4H 5C"])
+    );
+    assert_eq!(
+        winning_hands_with(&hands, RankingRules::High),
+        Some(vec!["KD KH KC 9S 9D"])
+    );
+}
+
+#[test]
+fn test_winning_hands_with_eight_or_better_may_have_no_winner() {
+    let hands = ["9S 5H 4D 3C 2S", "KS KH 3D 4C 5S"];
+    assert_eq!(
+        try_winning_hands_with(&hands, RankingRules::EightOrBetter),
+        Ok(vec![])
+    );
+    let hands = ["9S 5H 4D 3C 2S", "8S 7H 3D 4C 5C"];
+    assert_eq!(
+        winning_hands_with(&hands, RankingRules::EightOrBetter),
+        Some(vec!["8S 7H 3D 4C 5C"])
+    );
+}
+
+#[test]
+fn test_holdem_with_eight_or_better_may_have_no_winner() {
+    let rules = RankingRules::EightOrBetter;
+    let players = [hole("5C 6D"), hole("KD KH")];
+    assert_eq!(
+        holdem_winners_with(&cards("KS QH JD 9C 9S"), &players, rules),
+        Ok(vec![])
+    );
+    assert_eq!(
+        holdem_winners_with(&cards("2S 3H 4D KC QS"), &players, rules),
+        Ok(vec![0])
+    );
+}
+
+#[test]
+fn test_hand_rank_with() {
+    let hand = Hand::parse("AS 2H 3D 4C 5S").unwrap();
+    assert_eq!(hand.rank_with(RankingRules::High), hand.rank());
+    assert_eq!(
+        hand.rank_with(RankingRules::AceToFive),
+        rank(RankingRules::AceToFive, "AS 2H 3D 4C 5S")
+    );
+}
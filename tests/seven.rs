@@ -0,0 +1,121 @@
+#![cfg(feature = "std")]
+
+use poker::{best_hand, evaluate, evaluate_seven, Card, Deck, HandRank, Ranking, RankingRules};
+
+fn cards(cards: &str) -> [Card; 7] {
+    let cards: Vec<Card> = cards
+        .split_whitespace()
+        .map(|card| card.parse().expect("This card should parse"))
+        .collect();
+    let mut seven = [cards[0]; 7];
+    seven.copy_from_slice(&cards);
+    seven
+}
+
+/// The best five of seven cards found the slow way, by trying all 21 of them. Ties go to the first
+/// combination found, and the cards come out highest rank first, as `best_hand` gives them.
+fn every_five(cards: &[Card; 7]) -> (HandRank, [Card; 5]) {
+    let mut best: Option<(HandRank, [Card; 5])> = None;
+    for mask in 0u32..1 << 7 {
+        if mask.count_ones() != 5 {
+            continue;
+        }
+        let mut hand = [cards[0]; 5];
+        let chosen = (0..7).filter(|index| mask & (1 << index) != 0);
+        for (slot, index) in hand.iter_mut().zip(chosen) {
+            *slot = cards[index];
+        }
+        let rank = evaluate(&hand);
+        if best.is_none_or(|(best, _)| rank > best) {
+            best = Some((rank, hand));
+        }
+    }
+    let (rank, mut hand) = best.unwrap();
+    hand.sort_by_key(|card| std::cmp::Reverse(card.rank()));
+    (rank, hand)
+}
+
+#[test]
+fn test_examples() {
+    for (hand, ranking) in &[
+        ("AH KH QH JH TH 2C 3D", Ranking::StraightFlush),
+        ("AH 2H 3H 4H 5H 6C 7D", Ranking::StraightFlush),
+        ("9S 9H 9D 9C KH KD 2S", Ranking::FourOfAKind),
+        ("KH KD KS 6C 6D 6H 2C", Ranking::FullHouse),
+        ("KH KD KS 6C 6D 2H 2C", Ranking::FullHouse),
+        ("2H 9H JH 6H AH 8H 3H", Ranking::Flush),
+        ("AH 2D 3S 4C 5D KH KD", Ranking::Straight),
+        ("7H 7D 7S KC 2D 4H 9S", Ranking::ThreeOfAKind),
+        ("KH KD 5S 5C 2D 2H AD", Ranking::TwoPair),
+        ("TH TD AS QC 4D 2H 7S", Ranking::OnePair),
+        ("AH KD 9S 7C 3D 2H 4S", Ranking::HighCard),
+    ] {
+        let cards = cards(hand);
+        let rank = evaluate_seven(&cards);
+        assert_eq!(rank.ranking(), *ranking, "{}", hand);
+        assert_eq!(rank, every_five(&cards).0, "{}", hand);
+    }
+}
+
+#[test]
+fn test_agrees_with_best_hand_on_random_hands() {
+    for seed in 0..20_000 {
+        let mut deck = Deck::standard();
+        deck.shuffle(seed);
+        let dealt = deck.deal(7).unwrap();
+        let mut cards = [dealt[0]; 7];
+        cards.copy_from_slice(&dealt);
+        let best = every_five(&cards);
+        assert_eq!(evaluate_seven(&cards), best.0, "{:?}", cards);
+        assert_eq!(best_hand(&cards), best, "{:?}", cards);
+        assert_eq!(RankingRules::High.best_hand(&cards), best_hand(&cards));
+    }
+}
+
+#[test]
+fn test_best_hand_picks_the_cards_given_first() {
+    for (hand, best) in &[
+        ("KH KD KS 6C 6D 6H 2C", "KH KD KS 6C 6D"),
+        ("AH 2D 3S 4C 5D 5H 4H", "AH 5D 4C 3S 2D"),
+        ("2H 9H JH 6H AH 8H 3H", "AH JH 9H 8H 6H"),
+    ] {
+        let best: Vec<Card> = best
+            .split_whitespace()
+            .map(|card| card.parse().expect("This card should parse"))
+            .collect();
+        assert_eq!(best_hand(&cards(hand)).1[..], best[..], "{}", hand);
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "the seven cards must be different")]
+fn test_repeated_card_panics_in_debug_builds() {
+    evaluate_seven(&cards("AH AH KD QS JC 9H 8D"));
+}
+
+#[test]
+#[ignore = "brute force over all 133,784,560 seven card hands; run with --release"]
+fn test_agrees_with_best_hand_on_every_hand() {
+    let deck: Vec<Card> = Card::deck().collect();
+    let mut hands = 0u64;
+    let mut indices = [0, 1, 2, 3, 4, 5, 6];
+    loop {
+        let mut cards = [deck[0]; 7];
+        for (card, &index) in cards.iter_mut().zip(&indices) {
+            *card = deck[index];
+        }
+        assert_eq!(evaluate_seven(&cards), every_five(&cards).0, "{:?}", cards);
+        hands += 1;
+        match (0..7).rev().find(|&i| indices[i] < 52 - 7 + i) {
+            Some(i) => {
+                indices[i] += 1;
+                for j in i + 1..7 {
+                    indices[j] = indices[j - 1] + 1;
+                }
+            }
+            None => break,
+        }
+    }
+    assert_eq!(hands, 133_784_560);
+}
@@ -0,0 +1,170 @@
+#![cfg(feature = "std")]
+
+use poker::{
+    evaluate, holdem_winners_with, winning_hands_with, Card, HandRank, Rank, Ranking, RankingRules,
+};
+use std::collections::HashSet;
+
+mod common;
+use common::{cards, hole};
+
+const SHORT_DECK: RankingRules = RankingRules::ShortDeck {
+    trips_beat_straight: false,
+};
+const TRIPS_OVER_STRAIGHT: RankingRules = RankingRules::ShortDeck {
+    trips_beat_straight: true,
+};
+
+fn rank(rules: RankingRules, hand: &str) -> HandRank {
+    rules.evaluate(&cards(hand))
+}
+
+/// Checks that every hand beats the one before it under `rules`.
+fn assert_ascending(rules: RankingRules, table: &[&str]) {
+    for (i, weaker) in table.iter().enumerate() {
+        for stronger in &table[i + 1..] {
+            assert!(
+                rank(rules, weaker) < rank(rules, stronger),
+                "{} should lose to {} under {:?}",
+                weaker,
+                stronger,
+                rules
+            );
+        }
+    }
+}
+
+/// Short deck, weakest to strongest, with a straight above three of a kind.
+const STRAIGHT_OVER_TRIPS: &[&str] = &[
+    "AS KH QD JC 9S",
+    "6S 6H 7D 8C TS",
+    "7S 7H 6D 6C 8S",
+    "6S 6H 6D 7C 8S",
+    "AS AH AD KC QS",
+    "AS 6H 7D 8C 9S",
+    "6S 7H 8D 9C TS",
+    "TS JH QD KC AS",
+    "6S 6H 6D 7C 7S",
+    "AS AH AD KC KS",
+    "6H 7H 8H 9H JH",
+    "AH KH QH JH 9H",
+    "6S 6H 6D 6C 7S",
+    "AS 6S 7S 8S 9S",
+    "TH JH QH KH AH",
+];
+
+/// The same hands with three of a kind above a straight.
+const TRIPS_OVER_STRAIGHT_TABLE: &[&str] = &[
+    "AS KH QD JC 9S",
+    "6S 6H 7D 8C TS",
+    "7S 7H 6D 6C 8S",
+    "AS 6H 7D 8C 9S",
+    "6S 7H 8D 9C TS",
+    "TS JH QD KC AS",
+    "6S 6H 6D 7C 8S",
+    "AS AH AD KC QS",
+    "6S 6H 6D 7C 7S",
+    "AS AH AD KC KS",
+    "6H 7H 8H 9H JH",
+    "AH KH QH JH 9H",
+    "6S 6H 6D 6C 7S",
+    "AS 6S 7S 8S 9S",
+    "TH JH QH KH AH",
+];
+
+#[test]
+fn test_short_deck_order() {
+    assert_ascending(SHORT_DECK, STRAIGHT_OVER_TRIPS);
+}
+
+#[test]
+fn test_short_deck_order_with_trips_over_straight() {
+    assert_ascending(TRIPS_OVER_STRAIGHT, TRIPS_OVER_STRAIGHT_TABLE);
+}
+
+#[test]
+fn test_flush_beats_full_house_only_in_short_deck() {
+    let flush = "6H 7H 8H 9H JH";
+    let full_house = "AS AH AD KC KS";
+    assert!(rank(SHORT_DECK, flush) > rank(SHORT_DECK, full_house));
+    assert!(evaluate(&cards(flush)) < evaluate(&cards(full_house)));
+}
+
+#[test]
+fn test_short_deck_wheel() {
+    let wheel = rank(SHORT_DECK, "AS 6H 7D 8C 9S");
+    assert_eq!(wheel.ranking(), Ranking::Straight);
+    assert_eq!(wheel.tie_break().collect::<Vec<_>>(), vec![Rank::Nine]);
+    let straight_flush = rank(SHORT_DECK, "AS 6S 7S 8S 9S");
+    assert_eq!(straight_flush.ranking(), Ranking::StraightFlush);
+
+    // Under standard rules A-6-7-8-9 is ace high, and in short deck A-2-3-4-5 is no straight.
+    assert_eq!(
+        evaluate(&cards("AS 6H 7D 8C 9S")).ranking(),
+        Ranking::HighCard
+    );
+    assert_eq!(
+        rank(SHORT_DECK, "AS 2H 3D 4C 5S").ranking(),
+        Ranking::HighCard
+    );
+}
+
+#[test]
+fn test_categories_are_unchanged() {
+    // The short deck wheels are checked above.
+    for hand in STRAIGHT_OVER_TRIPS
+        .iter()
+        .filter(|hand| !hand.starts_with("AS 6"))
+    {
+        let standard = evaluate(&cards(hand)).ranking();
+        if standard != Ranking::HighCard {
+            assert_eq!(rank(SHORT_DECK, hand).ranking(), standard, "{}", hand);
+            assert_eq!(
+                rank(TRIPS_OVER_STRAIGHT, hand).ranking(),
+                standard,
+                "{}",
+                hand
+            );
+        }
+    }
+}
+
+#[test]
+fn test_short_deck_has_36_distinct_cards_from_six_up() {
+    let deck: Vec<Card> = Card::short_deck().collect();
+    assert_eq!(deck.len(), 36);
+    assert_eq!(deck.iter().collect::<HashSet<_>>().len(), 36);
+    assert!(deck.iter().all(|card| card.rank() >= Rank::Six));
+
+    let full: Vec<Card> = Card::deck().collect();
+    assert_eq!(full.len(), 52);
+    assert_eq!(full.iter().collect::<HashSet<_>>().len(), 52);
+    assert!(deck.iter().all(|card| full.contains(card)));
+}
+
+#[test]
+fn test_short_deck_winning_hands() {
+    let hands = ["6H 7H 8H 9H JH", "AS AH AD KC KS"];
+    assert_eq!(
+        winning_hands_with(&hands, SHORT_DECK),
+        Some(vec!["6H 7H 8H 9H JH"])
+    );
+    assert_eq!(
+        winning_hands_with(&hands, RankingRules::High),
+        Some(vec!["AS AH AD KC KS"])
+    );
+}
+
+#[test]
+fn test_short_deck_holdem() {
+    let board = cards("6H 7H 9H KS KD");
+    let players = [hole("AH TH"), hole("KC 9S"), hole("8S TD")];
+    assert_eq!(
+        holdem_winners_with(&board, &players, SHORT_DECK),
+        Ok(vec![0])
+    );
+    assert_eq!(
+        holdem_winners_with(&board, &players, RankingRules::High),
+        Ok(vec![1])
+    );
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "std")]
+
+use poker::{rank_hands, rank_parsed_hands, try_rank_hands, CardError, Hand, Ranking};
+
+#[test]
+fn test_every_hand_is_ranked() {
+    let hands = [
+        "4S 5S 7H 8D JC",
+        "2S 2H 7S 9H TH",
+        "3S 3H 3D 6C 8C",
+        "AS KS QS JS 9S",
+    ];
+    let standings = rank_hands(&hands).unwrap();
+    let order: Vec<(usize, usize, Ranking)> = standings
+        .iter()
+        .map(|standing| (standing.index, standing.position, standing.ranking))
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            (3, 1, Ranking::Flush),
+            (2, 2, Ranking::ThreeOfAKind),
+            (1, 3, Ranking::OnePair),
+            (0, 4, Ranking::HighCard),
+        ]
+    );
+}
+
+#[test]
+fn test_ties_share_a_position() {
+    let hands = [
+        "3S 4S 5D 6H JH",
+        "2S 2H 7S 9H TH",
+        "3H 4H 5C 6C JD",
+        "2D 2C 7D 9C TC",
+    ];
+    let positions: Vec<(usize, usize)> = rank_hands(&hands)
+        .unwrap()
+        .iter()
+        .map(|standing| (standing.index, standing.position))
+        .collect();
+    //Tied hands keep their input order, and the place after a tie is skipped.
+    assert_eq!(positions, vec![(1, 1), (3, 1), (0, 3), (2, 3)]);
+}
+
+#[test]
+fn test_standings_hold_the_same_references() {
+    let owned = ["4S 5S 7H 8D JC".to_string(), "2S 2H 7S 9H TH".to_string()];
+    let hands: Vec<&str> = owned.iter().map(String::as_str).collect();
+    let standings = rank_hands(&hands).unwrap();
+    for standing in &standings {
+        assert!(std::ptr::eq(standing.hand, hands[standing.index]));
+    }
+}
+
+#[test]
+fn test_first_place_matches_winning_hands() {
+    let hands = [
+        "4D 5S 6S 8D 3C",
+        "2S 4C 7S 9H 10H",
+        "3S 4S 5D 6H JH",
+        "3H 4H 5C 6C JD",
+    ];
+    let first: Vec<&str> = rank_hands(&hands)
+        .unwrap()
+        .iter()
+        .filter(|standing| standing.position == 1)
+        .map(|standing| standing.hand)
+        .collect();
+    assert_eq!(Some(first), poker::winning_hands(&hands));
+}
+
+#[test]
+fn test_pre_parsed_hands() {
+    let hands: Vec<Hand> = ["2S 2H 7S 9H TH", "AS KS QS JS 9S"]
+        .iter()
+        .map(|hand| hand.parse().unwrap())
+        .collect();
+    let standings = rank_parsed_hands(&hands);
+    assert_eq!(standings[0].index, 1);
+    assert!(std::ptr::eq(standings[0].hand, &hands[1]));
+    assert_eq!(standings[0].rank, hands[1].rank());
+    assert_eq!(standings[1].position, 2);
+}
+
+#[test]
+fn test_no_hands() {
+    assert_eq!(rank_hands(&[]), Some(vec![]));
+}
+
+#[test]
+fn test_invalid_hands() {
+    assert_eq!(rank_hands(&["4S 5S 7H 8D JC", "4S 2H"]), None);
+    let error = try_rank_hands(&["4S 5S 7H 8D JC", "4S 2H 3H 6H 9H"]).unwrap_err();
+    assert_eq!(error.hand, 1);
+    assert!(matches!(
+        error.source,
+        CardError::CardSharedBetweenHands { hand: 0, .. }
+    ));
+}
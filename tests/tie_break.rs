@@ -0,0 +1,289 @@
+#![cfg(feature = "std")]
+
+use poker::{Card, Hand, HandRank, Rank, Ranking, Suit};
+use std::convert::TryFrom;
+
+/// Every category with hands listed from weakest to strongest, each strictly beating the one
+/// before it. The last hand of one category is always beaten by the first of the next.
+const REFERENCE: &[(Ranking, &[&str])] = &[
+    (
+        Ranking::HighCard,
+        &[
+            "2S 3H 4D 5C 7S",
+            "2S 3H 4D 6C 7S",
+            "2S 3H 5D 6C 7S",
+            "2S 4H 5D 6C 7S",
+            "3S 4H 5D 6C 8S",
+            "9S 4H 5D 6C 8S",
+            "KS QH JD 10C 8S",
+            "AS KH QD JC 9S",
+        ],
+    ),
+    (
+        Ranking::OnePair,
+        &[
+            "2S 2H 5D 6C 7S",
+            "2S 2H 5D 6C 8S",
+            "2S 2H 3D 4C 9S",
+            "3S 3H 2D 4C 5S",
+            "3S 3H 2D 4C 6S",
+            "KS KH 2D 3C 4S",
+            "KS KH AD 3C 4S",
+            "AS AH 2D 3C 4S",
+            "AS AH KD QC JS",
+        ],
+    ),
+    (
+        Ranking::TwoPair,
+        &[
+            "3S 3H 2D 2C 4S",
+            "3S 3H 2D 2C AS",
+            "4S 4H 2D 2C AS",
+            "4S 4H 3D 3C 2S",
+            "KS KH 2D 2C AS",
+            "KS KH 3D 3C 4S",
+            "KS KH QD QC 2S",
+            "AS AH 2D 2C 3S",
+            "AS AH KD KC QS",
+        ],
+    ),
+    (
+        Ranking::ThreeOfAKind,
+        &[
+            "2S 2H 2D 3C 4S",
+            "2S 2H 2D 3C 5S",
+            "2S 2H 2D 4C 5S",
+            "2S 2H 2D AC KS",
+            "3S 3H 3D 2C 4S",
+            "AS AH AD 7C 3S",
+            "AS AH AD 7C 4S",
+            "AS AH AD 8C 2S",
+            "AS AH AD KC QS",
+        ],
+    ),
+    (
+        Ranking::Straight,
+        &[
+            "AS 2H 3D 4C 5S",
+            "2S 3H 4D 5C 6S",
+            "3S 4H 5D 6C 7S",
+            "9S 10H JD QC KS",
+            "10S JH QD KC AS",
+        ],
+    ),
+    (
+        Ranking::Flush,
+        &[
+            "2S 3S 4S 5S 7S",
+            "2S 3S 4S 6S 7S",
+            "2H 3H 4H 5H 8H",
+            "9S 3S 4S 5S 7S",
+            "AS KS QS JS 9S",
+        ],
+    ),
+    (
+        Ranking::FullHouse,
+        &[
+            "2S 2H 2D 3C 3S",
+            "2S 2H 2D AC AS",
+            "3S 3H 3D 2C 2S",
+            "AS AH AD 2C 2S",
+            "AS AH AD KC KS",
+        ],
+    ),
+    (
+        Ranking::FourOfAKind,
+        &[
+            "2S 2H 2D 2C 3S",
+            "2S 2H 2D 2C AS",
+            "3S 3H 3D 3C 2S",
+            "AS AH AD AC 2S",
+            "AS AH AD AC KS",
+        ],
+    ),
+    (
+        Ranking::StraightFlush,
+        &[
+            "AS 2S 3S 4S 5S",
+            "2H 3H 4H 5H 6H",
+            "9D 10D JD QD KD",
+            "10C JC QC KC AC",
+        ],
+    ),
+];
+
+const RANKS: [Rank; 13] = [
+    Rank::Two,
+    Rank::Three,
+    Rank::Four,
+    Rank::Five,
+    Rank::Six,
+    Rank::Seven,
+    Rank::Eight,
+    Rank::Nine,
+    Rank::Ten,
+    Rank::Jack,
+    Rank::Queen,
+    Rank::King,
+    Rank::Ace,
+];
+
+const SUITS: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+
+fn parse(hand: &str) -> Vec<Card> {
+    Hand::parse(hand)
+        .expect("This hand should parse")
+        .cards()
+        .to_vec()
+}
+
+fn hand(cards: &[Card]) -> Hand {
+    Hand::try_from(cards).expect("This hand should be valid")
+}
+
+fn rank(cards: &[Card]) -> HandRank {
+    hand(cards).rank()
+}
+
+#[test]
+fn test_reference_hands_fall_into_their_category() {
+    for (ranking, hands) in REFERENCE {
+        for hand in hands.iter() {
+            assert_eq!(Hand::parse(hand).unwrap().ranking(), *ranking, "{}", hand);
+            assert_eq!(rank(&parse(hand)).ranking(), *ranking, "{}", hand);
+        }
+    }
+}
+
+#[test]
+fn test_hand_rank_follows_reference_ordering() {
+    let hands: Vec<&str> = REFERENCE
+        .iter()
+        .flat_map(|(_, hands)| hands.iter().copied())
+        .collect();
+    for (i, weaker) in hands.iter().enumerate() {
+        for stronger in &hands[i + 1..] {
+            assert!(
+                rank(&parse(weaker)) < rank(&parse(stronger)),
+                "{} should lose to {}",
+                weaker,
+                stronger
+            );
+        }
+    }
+}
+
+#[test]
+fn test_second_pair_beats_kicker() {
+    let lhs = parse("KS KH 2D 2C AS");
+    let rhs = parse("KD KC 3D 3C 4S");
+    assert!(rank(&lhs) < rank(&rhs));
+}
+
+type ReferenceKey = (u8, Vec<u8>);
+
+/// An independent, deliberately naive ordering key: category first, then the values of each
+/// group, largest group first. Values run from 2 (two) to 14 (ace).
+fn reference_key(values: &[u8], flush: bool) -> ReferenceKey {
+    let mut groups: Vec<(usize, u8)> = Vec::new();
+    for value in (2..=14).rev() {
+        let count = values.iter().filter(|&&v| v == value).count();
+        if count > 0 {
+            groups.push((count, value));
+        }
+    }
+    groups.sort_by(|a, b| b.cmp(a));
+    let counts: Vec<usize> = groups.iter().map(|group| group.0).collect();
+    let ordered: Vec<u8> = groups.iter().map(|group| group.1).collect();
+
+    let straight = if counts.len() == 5 && ordered[0] - ordered[4] == 4 {
+        Some(ordered[0])
+    } else if ordered == [14, 5, 4, 3, 2] {
+        Some(5)
+    } else {
+        None
+    };
+
+    match (counts.as_slice(), straight, flush) {
+        (_, Some(high), true) => (9, vec![high]),
+        ([4, 1], _, _) => (8, ordered),
+        ([3, 2], _, _) => (7, ordered),
+        (_, None, true) => (6, ordered),
+        (_, Some(high), false) => (5, vec![high]),
+        ([3, 1, 1], _, _) => (4, ordered),
+        ([2, 2, 1], _, _) => (3, ordered),
+        ([2, 1, 1, 1], _, _) => (2, ordered),
+        _ => (1, ordered),
+    }
+}
+
+/// Every distinct five card hand by rank, plus every distinct flush.
+fn all_rank_patterns() -> Vec<(Vec<Card>, ReferenceKey)> {
+    let mut patterns = Vec::new();
+    let mut indices = [0usize; 5];
+    fn next(indices: &mut [usize; 5]) -> bool {
+        // Non-decreasing sequences of rank indices, i.e. multisets.
+        for position in (0..5).rev() {
+            if indices[position] < 12 {
+                indices[position] += 1;
+                for later in position + 1..5 {
+                    indices[later] = indices[position];
+                }
+                return true;
+            }
+        }
+        false
+    }
+    loop {
+        let counts = (0..13).map(|rank| indices.iter().filter(|&&i| i == rank).count());
+        if counts.clone().all(|count| count <= 4) {
+            let values: Vec<u8> = indices.iter().map(|&i| i as u8 + 2).collect();
+            let distinct = counts.filter(|&count| count > 0).count() == 5;
+
+            // Cards sharing a rank take successive suits. Five distinct ranks would otherwise all
+            // be spades, so the first card is moved to hearts to keep the hand suited only when
+            // asked for.
+            let cards: Vec<Card> = indices
+                .iter()
+                .enumerate()
+                .map(|(position, &i)| {
+                    let copies = indices[..position].iter().filter(|&&j| j == i).count();
+                    let suit = if distinct && position == 0 { 1 } else { copies };
+                    Card::new(RANKS[i], SUITS[suit])
+                })
+                .collect();
+            patterns.push((cards, reference_key(&values, false)));
+
+            if distinct {
+                let suited = indices
+                    .iter()
+                    .map(|&i| Card::new(RANKS[i], Suit::Spades))
+                    .collect();
+                patterns.push((suited, reference_key(&values, true)));
+            }
+        }
+        if !next(&mut indices) {
+            return patterns;
+        }
+    }
+}
+
+#[test]
+fn test_every_rank_pattern_follows_reference_ordering() {
+    let mut patterns = all_rank_patterns();
+    assert_eq!(patterns.len(), 6175 + 1287);
+    patterns.sort_by(|a, b| a.1.cmp(&b.1));
+
+    for pair in patterns.windows(2) {
+        let (lhs, lhs_key) = &pair[0];
+        let (rhs, rhs_key) = &pair[1];
+        let expected = lhs_key.cmp(rhs_key);
+        assert_eq!(
+            rank(lhs).cmp(&rank(rhs)),
+            expected,
+            "{:?} vs {:?}",
+            lhs,
+            rhs
+        );
+    }
+}
@@ -0,0 +1,175 @@
+#![cfg(feature = "std")]
+
+use poker::{
+    evaluate, evaluate_wild, try_winning_wild_hands, winning_hands, winning_wild_hands, Card,
+    CardError, JokerRule, Rank, Ranking, WildCard, WildRules,
+};
+use std::convert::TryFrom;
+
+const JOKERS_WILD: WildRules = WildRules {
+    joker: JokerRule::Wild,
+    wild_rank: None,
+};
+const BUG: WildRules = WildRules {
+    joker: JokerRule::Bug,
+    wild_rank: None,
+};
+const DEUCES_WILD: WildRules = WildRules {
+    joker: JokerRule::Wild,
+    wild_rank: Some(Rank::Two),
+};
+
+fn wild_cards(hand: &str) -> [WildCard; 5] {
+    let cards: Vec<WildCard> = hand
+        .split_whitespace()
+        .map(|card| card.parse().expect("This card should parse"))
+        .collect();
+    <[WildCard; 5]>::try_from(&cards[..]).expect("Five cards")
+}
+
+fn cards(hand: &str) -> Vec<Card> {
+    hand.split_whitespace()
+        .map(|card| card.parse().expect("This card should parse"))
+        .collect()
+}
+
+fn ranking(hand: &str, rules: WildRules) -> Ranking {
+    evaluate_wild(&wild_cards(hand), rules).0.ranking()
+}
+
+#[test]
+fn test_parse_jokers() {
+    for joker in ["Jk", "JK", "jk", "XX", "xx", "Joker"].iter() {
+        assert_eq!(joker.parse::<WildCard>(), Ok(WildCard::Joker), "{}", joker);
+    }
+    assert_eq!(
+        "JH".parse::<WildCard>(),
+        Ok(WildCard::Natural("JH".parse().unwrap()))
+    );
+    assert!("JX".parse::<WildCard>().is_err());
+    assert_eq!(WildCard::Joker.to_string(), "Jk");
+    assert_eq!(format!("{:#}", WildCard::Joker), "Joker");
+    assert_eq!(WildCard::Joker.natural(), None);
+}
+
+#[test]
+fn test_without_wilds_matches_evaluate() {
+    let hand = "4S 5S 7H 8D JC";
+    let (rank, played) = evaluate_wild(&wild_cards(hand), DEUCES_WILD);
+    assert_eq!(rank, evaluate(&cards(hand)));
+    assert_eq!(played.to_vec(), cards(hand));
+}
+
+#[test]
+fn test_five_of_a_kind() {
+    let (rank, played) = evaluate_wild(&wild_cards("AS AH AD AC Jk"), JOKERS_WILD);
+    assert_eq!(rank.ranking(), Ranking::FiveOfAKind);
+    assert_eq!(played[4].rank(), Rank::Ace);
+    assert_eq!(ranking("7S 7H 7D Jk Jk", JOKERS_WILD), Ranking::FiveOfAKind);
+    assert_eq!(ranking("7S 7H 2D 2C 2S", DEUCES_WILD), Ranking::FiveOfAKind);
+}
+
+#[test]
+fn test_five_of_a_kind_beats_a_royal_flush() {
+    let five = evaluate_wild(&wild_cards("9S 9H 9D 9C Jk"), JOKERS_WILD).0;
+    let royal = evaluate_wild(&wild_cards("TH JH QH KH AH"), JOKERS_WILD).0;
+    assert!(five > royal);
+}
+
+#[test]
+fn test_joker_reports_the_card_it_became() {
+    let (rank, played) = evaluate_wild(&wild_cards("TH JH Jk KH AH"), JOKERS_WILD);
+    assert_eq!(rank.ranking(), Ranking::StraightFlush);
+    assert_eq!(played.to_vec(), cards("TH JH QH KH AH"));
+
+    // Not suited, so only the rank matters, but the joker still avoids repeating a card.
+    let (rank, played) = evaluate_wild(&wild_cards("KS KH 4D 7C Jk"), JOKERS_WILD);
+    assert_eq!(rank.ranking(), Ranking::ThreeOfAKind);
+    assert_eq!(played[4].rank(), Rank::King);
+    assert!(!played[..4].contains(&played[4]));
+}
+
+#[test]
+fn test_wild_flush_takes_the_best_missing_card() {
+    let (rank, played) = evaluate_wild(&wild_cards("AH KH 5H 3H Jk"), JOKERS_WILD);
+    assert_eq!(rank.ranking(), Ranking::Flush);
+    assert_eq!(played[4], "QH".parse().unwrap());
+}
+
+#[test]
+fn test_bug_only_counts_as_ace_straight_or_flush() {
+    // Fully wild, the joker makes trips; the bug can only be an ace.
+    assert_eq!(
+        ranking("KS KH 4D 7C Jk", JOKERS_WILD),
+        Ranking::ThreeOfAKind
+    );
+    let (rank, played) = evaluate_wild(&wild_cards("KS KH 4D 7C Jk"), BUG);
+    assert_eq!(rank.ranking(), Ranking::OnePair);
+    assert_eq!(played[4].rank(), Rank::Ace);
+
+    assert_eq!(ranking("AS AH 4D 7C Jk", BUG), Ranking::ThreeOfAKind);
+    assert_eq!(ranking("AS AH AD AC Jk", BUG), Ranking::FiveOfAKind);
+
+    let (rank, played) = evaluate_wild(&wild_cards("9S TH JD QC Jk"), BUG);
+    assert_eq!(rank.ranking(), Ranking::Straight);
+    assert_eq!(played[4].rank(), Rank::King);
+    assert_eq!(ranking("2H 7H 9H JH Jk", BUG), Ranking::Flush);
+}
+
+#[test]
+fn test_deuces_wild() {
+    assert_eq!(ranking("2S 2H 5D 6C 7S", DEUCES_WILD), Ranking::Straight);
+    assert_eq!(
+        ranking("2S 2H 5S 6S 7S", DEUCES_WILD),
+        Ranking::StraightFlush
+    );
+    assert_eq!(
+        ranking("2S KH KD 3C 7S", DEUCES_WILD),
+        Ranking::ThreeOfAKind
+    );
+    // Jokers stay wild alongside the deuces.
+    assert_eq!(ranking("2S KH KD Jk 7S", DEUCES_WILD), Ranking::FourOfAKind);
+}
+
+#[test]
+fn test_winning_wild_hands() {
+    let hands = ["AS AH AD AC KS", "9S 9H 9D Jk Jk"];
+    assert_eq!(
+        winning_wild_hands(&hands, JOKERS_WILD),
+        Some(vec!["9S 9H 9D Jk Jk"])
+    );
+    let hands = ["AS AH AD AC KS", "9S 9H 9D 9C 2S"];
+    assert_eq!(winning_hands(&hands), Some(vec!["AS AH AD AC KS"]));
+    assert_eq!(
+        winning_wild_hands(&hands, DEUCES_WILD),
+        Some(vec!["9S 9H 9D 9C 2S"])
+    );
+}
+
+#[test]
+fn test_winning_wild_hands_compact_and_errors() {
+    assert_eq!(
+        winning_wild_hands(&["AsAhAdAcJk", "KsKhKdKcXX"], JOKERS_WILD),
+        Some(vec!["AsAhAdAcJk"])
+    );
+    let error =
+        try_winning_wild_hands(&["AS AH AD AC Jk", "AS KH KD KC Jk"], JOKERS_WILD).unwrap_err();
+    assert_eq!(error.hand, 1);
+    assert!(matches!(
+        error.source,
+        CardError::CardSharedBetweenHands { hand: 0, .. }
+    ));
+}
+
+#[test]
+fn test_joker_written_out_in_a_hand() {
+    let hands = ["Joker AS KS QS JS", "AH AD AC KH KD"];
+    assert_eq!(
+        winning_wild_hands(&hands, JOKERS_WILD),
+        Some(vec!["Joker AS KS QS JS"])
+    );
+    assert_eq!(
+        winning_wild_hands(&["AsJOKERKsQsJs", "AH AD AC KH KD"], JOKERS_WILD),
+        Some(vec!["AsJOKERKsQsJs"])
+    );
+}